@@ -1,222 +1,7423 @@
-use std::fs::{metadata, Metadata};
-use std::path::{Path, PathBuf};
-use std::io::Result;
-use sha2::{Digest, Sha256};
+use std::ffi::OsStr;
+use std::fs::{metadata, DirEntry, FileTimes, Metadata, OpenOptions};
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, BinaryHeap, HashMap};
+use std::path::{Path, PathBuf, MAIN_SEPARATOR, MAIN_SEPARATOR_STR};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::ops::Range;
+use std::io::{BufRead, BufReader, Error, ErrorKind, Read, Result, Seek, SeekFrom, Write};
+use std::time::{Duration, SystemTime};
+use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
 
-pub struct File {
-    path: PathBuf,
+#[cfg(all(unix, feature = "xattr"))]
+mod xattrs;
+#[cfg(feature = "json")]
+mod json;
+#[cfg(feature = "trash")]
+mod trash;
+#[cfg(feature = "flate2")]
+mod gzip;
+#[cfg(feature = "tokio")]
+mod stream;
+#[cfg(feature = "mmap")]
+mod mmap;
+#[cfg(feature = "csv")]
+mod csv;
+#[cfg(feature = "timeout")]
+mod timeout;
+#[cfg(feature = "toml")]
+mod toml;
+#[cfg(feature = "notify")]
+mod watch;
+#[cfg(feature = "rayon")]
+mod parallel;
+
+#[cfg(feature = "mmap")]
+pub use mmap::{FileContents, Mmap};
+#[cfg(feature = "notify")]
+pub use watch::{FileEvent, WatchGuard};
+
+/// 스트리밍 작업에서 사용하는 기본 버퍼 크기(64 KiB)입니다.
+const DEFAULT_BUFFER_SIZE: usize = 64 * 1024;
+
+/// 텍스트 파일 앞에 붙는 UTF-8 BOM(U+FEFF)입니다.
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// 지원하는 해시 알고리즘입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum HashAlgorithm {
+    Sha224,
+    Sha256,
+    Sha384,
+    Sha512,
+}
+
+impl HashAlgorithm {
+    /// `foo.iso.sha256`처럼 체크섬 파일에 관례적으로 쓰이는 확장자를 반환합니다.
+    pub fn extension(&self) -> &'static str {
+        match self {
+            HashAlgorithm::Sha224 => "sha224",
+            HashAlgorithm::Sha256 => "sha256",
+            HashAlgorithm::Sha384 => "sha384",
+            HashAlgorithm::Sha512 => "sha512",
+        }
+    }
+}
+
+/// 파일 시스템과 무관하게 바이트를 점진적으로 해싱하는 해셔입니다.
+/// `File::hash_with`와 동일한 알고리즘을 사용합니다.
+pub struct Hasher {
+    inner: HasherInner,
+}
+
+enum HasherInner {
+    Sha224(Sha224),
+    Sha256(Sha256),
+    Sha384(Sha384),
+    Sha512(Sha512),
+}
+
+impl Hasher {
+    pub fn new(algo: HashAlgorithm) -> Self {
+        let inner = match algo {
+            HashAlgorithm::Sha224 => HasherInner::Sha224(Sha224::new()),
+            HashAlgorithm::Sha256 => HasherInner::Sha256(Sha256::new()),
+            HashAlgorithm::Sha384 => HasherInner::Sha384(Sha384::new()),
+            HashAlgorithm::Sha512 => HasherInner::Sha512(Sha512::new()),
+        };
+
+        Hasher { inner }
+    }
+
+    /// 해싱할 바이트를 추가합니다.
+    pub fn update(&mut self, data: &[u8]) {
+        match &mut self.inner {
+            HasherInner::Sha224(h) => h.update(data),
+            HasherInner::Sha256(h) => h.update(data),
+            HasherInner::Sha384(h) => h.update(data),
+            HasherInner::Sha512(h) => h.update(data),
+        }
+    }
+
+    /// 해싱을 마치고 소문자 16진수 문자열을 반환합니다.
+    pub fn finalize(self) -> String {
+        to_hex(&self.finalize_bytes())
+    }
+
+    /// 해싱을 마치고 원시 다이제스트 바이트를 반환합니다.
+    fn finalize_bytes(self) -> Vec<u8> {
+        match self.inner {
+            HasherInner::Sha224(h) => h.finalize().to_vec(),
+            HasherInner::Sha256(h) => h.finalize().to_vec(),
+            HasherInner::Sha384(h) => h.finalize().to_vec(),
+            HasherInner::Sha512(h) => h.finalize().to_vec(),
+        }
+    }
+}
+
+// 바이트 배열을 소문자 16진수 문자열로 변환합니다.
+fn to_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+// 디렉터리 항목을 이름순으로 정렬하여 반환합니다.
+fn read_dir_sorted(dir: &Path) -> Result<Vec<DirEntry>> {
+    let mut entries = std::fs::read_dir(dir)?.collect::<Result<Vec<_>>>()?;
+    entries.sort_by_key(|entry| entry.file_name());
+    Ok(entries)
+}
+
+// 타임스탬프를 변경하기 위한 핸들을 엽니다.
+// 읽기 전용 파일에도 적용할 수 있도록 쓰기 권한을 요구하지 않습니다.
+fn open_for_times(path: &Path) -> Result<std::fs::File> {
+    #[cfg(windows)]
+    {
+        use std::os::windows::fs::OpenOptionsExt;
+        // FILE_WRITE_ATTRIBUTES
+        OpenOptions::new().access_mode(0x100).open(path)
+    }
+
+    #[cfg(not(windows))]
+    {
+        std::fs::File::open(path)
+    }
+}
+
+// 파일이나 디렉터리의 `BY_HANDLE_FILE_INFORMATION`(링크 수, 볼륨 일련번호 등)을 조회합니다.
+// 디렉터리도 열 수 있도록 접근 권한 없이 `FILE_FLAG_BACKUP_SEMANTICS`로 엽니다.
+#[cfg(windows)]
+fn file_information(path: &Path) -> Result<windows_sys::Win32::Storage::FileSystem::BY_HANDLE_FILE_INFORMATION> {
+    use std::os::windows::fs::OpenOptionsExt;
+    use std::os::windows::io::AsRawHandle;
+    use windows_sys::Win32::Storage::FileSystem::{
+        BY_HANDLE_FILE_INFORMATION, FILE_FLAG_BACKUP_SEMANTICS, GetFileInformationByHandle,
+    };
+
+    let handle = OpenOptions::new().access_mode(0).custom_flags(FILE_FLAG_BACKUP_SEMANTICS).open(path)?;
+    let mut info = std::mem::MaybeUninit::<BY_HANDLE_FILE_INFORMATION>::uninit();
+    // SAFETY: 유효한 파일 핸들과 구조체 크기의 버퍼를 전달합니다.
+    if unsafe { GetFileInformationByHandle(handle.as_raw_handle(), info.as_mut_ptr()) } == 0 {
+        return Err(Error::last_os_error());
+    }
+    // SAFETY: 호출이 성공했으므로 구조체가 초기화되었습니다.
+    Ok(unsafe { info.assume_init() })
+}
+
+// 경로 구분자나 `.`, `..` 없이 하나의 파일 이름으로만 이루어졌는지 확인합니다.
+fn validate_file_name(name: &str) -> Result<()> {
+    if name.contains(['/', '\\']) || Path::new(name).file_name() != Some(name.as_ref()) {
+        return Err(Error::new(
+            ErrorKind::InvalidInput,
+            format!("파일 이름으로 사용할 수 없습니다: {}", name),
+        ));
+    }
+
+    Ok(())
+}
+
+// 임시 이름에 쓸 무작위 값을 만듭니다. 시각, 프로세스 ID, 호출 횟수를 무작위 키로 해싱합니다.
+fn random_u64() -> u64 {
+    use std::hash::{BuildHasher, RandomState};
+
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let nanos = SystemTime::now().duration_since(SystemTime::UNIX_EPOCH).unwrap_or_default().as_nanos();
+    let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+    RandomState::new().hash_one((nanos, unique, std::process::id()))
+}
+
+// 다른 핸들이 파일을 잠시 열고 있어 발생하는, 재시도하면 해결될 수 있는 오류인지 확인합니다.
+// Windows의 ERROR_SHARING_VIOLATION(32)과 ERROR_LOCK_VIOLATION(33)은 std에서 `PermissionDenied`로 바뀌지 않으므로 따로 확인합니다.
+fn is_transient_lock_error(e: &Error) -> bool {
+    #[cfg(windows)]
+    {
+        use windows_sys::Win32::Foundation::{ERROR_LOCK_VIOLATION, ERROR_SHARING_VIOLATION};
+
+        let code = e.raw_os_error();
+        if code == Some(ERROR_SHARING_VIOLATION as i32) || code == Some(ERROR_LOCK_VIOLATION as i32) {
+            return true;
+        }
+    }
+
+    e.kind() == ErrorKind::PermissionDenied
+}
+
+// 대상 경로와 같은 디렉터리 안에 겹치지 않는 임시 파일 경로를 만듭니다.
+fn temp_path_for(path: &Path) -> PathBuf {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+
+    let name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+    let unique = COUNTER.fetch_add(1, Ordering::Relaxed);
+    path.with_file_name(format!(".{}.{}.{}.tmp", name, std::process::id(), unique))
+}
+
+// 대상 경로가 이미 있으면 덮어쓰지 않고 `AlreadyExists` 오류로 실패하는 이름 변경입니다.
+// Linux에서는 `renameat2(RENAME_NOREPLACE)`를, 그 밖에서는 파일에 한해 하드 링크 후 원본 삭제를 사용해 원자적으로 처리하며,
+// 둘 다 쓸 수 없는 경우(지원하지 않는 파일 시스템, 다른 플랫폼의 디렉터리)에만 확인 후 이름을 바꿉니다.
+fn rename_noreplace(src: &Path, dest: &Path) -> Result<()> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::ffi::CString;
+        use std::os::unix::ffi::OsStrExt;
+
+        let to_c = |path: &Path| {
+            CString::new(path.as_os_str().as_bytes()).map_err(|e| Error::new(ErrorKind::InvalidInput, e))
+        };
+        let (c_src, c_dest) = (to_c(src)?, to_c(dest)?);
+        // SAFETY: 두 포인터 모두 NUL로 끝나는 유효한 C 문자열을 가리킵니다.
+        let result = unsafe {
+            libc::renameat2(libc::AT_FDCWD, c_src.as_ptr(), libc::AT_FDCWD, c_dest.as_ptr(), libc::RENAME_NOREPLACE)
+        };
+        if result == 0 {
+            return Ok(());
+        }
+
+        // 커널이나 파일 시스템이 플래그를 지원하지 않을 때만 아래의 대체 방식을 사용합니다.
+        let err = Error::last_os_error();
+        if !matches!(err.raw_os_error(), Some(libc::EINVAL | libc::ENOSYS)) {
+            return Err(err);
+        }
+    }
+
+    if std::fs::symlink_metadata(src)?.is_file() {
+        match std::fs::hard_link(src, dest) {
+            Ok(()) => return std::fs::remove_file(src),
+            Err(e) if matches!(e.kind(), ErrorKind::AlreadyExists | ErrorKind::CrossesDevices) => return Err(e),
+            Err(_) => {}
+        }
+    }
+
+    if std::fs::symlink_metadata(dest).is_ok() {
+        return Err(Error::from(ErrorKind::AlreadyExists));
+    }
+    std::fs::rename(src, dest)
+}
+
+// 파일 시스템의 Copy-on-Write 복제를 시도하고, 복제했으면 `true`를 반환합니다.
+// 대상 경로는 아직 존재하지 않아야 하며(임시 경로), 복제하지 못하면 만든 파일을 정리한 뒤 `false`를 반환합니다.
+fn try_reflink(src: &Path, dest: &Path) -> Result<bool> {
+    #[cfg(target_os = "linux")]
+    {
+        use std::os::fd::AsRawFd;
+
+        let source = std::fs::File::open(src)?;
+        let target = OpenOptions::new().write(true).create_new(true).open(dest)?;
+        // SAFETY: 두 파일 디스크립터 모두 유효하며 FICLONE은 원본 디스크립터 값만 인자로 받습니다.
+        let result = unsafe { libc::ioctl(target.as_raw_fd(), libc::FICLONE, source.as_raw_fd()) };
+        if result == 0 {
+            target.set_permissions(source.metadata()?.permissions())?;
+            return Ok(true);
+        }
+
+        drop(target);
+        std::fs::remove_file(dest)?;
+        Ok(false)
+    }
+
+    #[cfg(target_os = "macos")]
+    {
+        use std::ffi::CString;
+        use std::os::unix::ffi::OsStrExt;
+
+        let to_c = |path: &Path| {
+            CString::new(path.as_os_str().as_bytes()).map_err(|e| Error::new(ErrorKind::InvalidInput, e))
+        };
+        let (src, dest) = (to_c(src)?, to_c(dest)?);
+        // SAFETY: 두 포인터 모두 NUL로 끝나는 유효한 C 문자열을 가리킵니다.
+        Ok(unsafe { libc::clonefile(src.as_ptr(), dest.as_ptr(), 0) } == 0)
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "macos")))]
+    {
+        let _ = (src, dest);
+        Ok(false)
+    }
+}
+
+// 하나의 버퍼를 재사용하며 Reader의 내용을 Writer로 옮기고 옮긴 바이트 수를 반환합니다.
+fn copy_stream<R: Read, W: Write>(mut reader: R, mut writer: W, buffer_size: usize) -> Result<u64> {
+    let mut buffer = vec![0u8; buffer_size];
+    let mut total = 0u64;
+
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        writer.write_all(&buffer[..read])?;
+        total += read as u64;
+    }
+
+    writer.flush()?;
+    Ok(total)
+}
+
+// 버퍼가 가득 차거나 EOF에 도달할 때까지 읽고 읽은 바이트 수를 반환합니다.
+fn read_full<R: Read>(reader: &mut R, buf: &mut [u8]) -> Result<usize> {
+    let mut filled = 0;
+
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(read) => filled += read,
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(filled)
+}
+
+// Reader에서 한 줄을 읽어 줄 끝의 `\n`과 `\r`을 제거해 반환하며, EOF이면 `None`을 반환합니다.
+fn read_line_from<R: BufRead>(reader: &mut R) -> Result<Option<String>> {
+    let mut line = Vec::new();
+
+    if reader.read_until(b'\n', &mut line)? == 0 {
+        return Ok(None);
+    }
+    if line.last() == Some(&b'\n') {
+        line.pop();
+        if line.last() == Some(&b'\r') {
+            line.pop();
+        }
+    }
+
+    String::from_utf8(line)
+        .map(Some)
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e))
+}
+
+// Reader에서 최대 `k`줄까지만 읽어 반환합니다. `k`줄을 채우면 더 읽지 않습니다.
+fn head_lines_from<R: BufRead>(reader: &mut R, k: usize) -> Result<Vec<String>> {
+    let mut lines = Vec::with_capacity(k.min(1024));
+
+    while lines.len() < k {
+        match read_line_from(reader)? {
+            Some(line) => lines.push(line),
+            None => break,
+        }
+    }
+
+    Ok(lines)
+}
+
+// UTF-16 바이트열을 지정한 바이트 순서로 해석하고, 맨 앞의 BOM(U+FEFF)은 제거합니다.
+fn decode_utf16(bytes: &[u8], big_endian: bool) -> Result<String> {
+    if !bytes.len().is_multiple_of(2) {
+        return Err(Error::new(ErrorKind::InvalidData, "UTF-16 데이터의 바이트 수가 홀수입니다"));
+    }
+
+    let units = bytes.chunks_exact(2).map(|pair| {
+        let pair = [pair[0], pair[1]];
+        if big_endian { u16::from_be_bytes(pair) } else { u16::from_le_bytes(pair) }
+    });
+    let decoded = char::decode_utf16(units)
+        .collect::<std::result::Result<String, _>>()
+        .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+
+    Ok(match decoded.strip_prefix('\u{FEFF}') {
+        Some(rest) => rest.to_string(),
+        None => decoded,
+    })
+}
+
+/// 임의의 Reader에서 읽은 바이트의 해시 값을 반환합니다.
+/// `std::io::stdin().lock()`처럼 파일이 아닌 스트림도 해싱할 수 있습니다.
+pub fn hash_reader<R: Read>(reader: R, algo: HashAlgorithm) -> Result<String> {
+    Ok(to_hex(&digest_reader(reader, algo, DEFAULT_BUFFER_SIZE)?))
+}
+
+// Reader를 버퍼 단위로 읽어 원시 다이제스트 바이트를 계산합니다.
+fn digest_reader<R: Read>(mut reader: R, algo: HashAlgorithm, buffer_size: usize) -> Result<Vec<u8>> {
+    let mut hasher = Hasher::new(algo);
+    let mut buffer = vec![0u8; buffer_size];
+
+    loop {
+        let read = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(read) => read,
+            Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        };
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(hasher.finalize_bytes())
+}
+
+/// 한 번의 메타데이터 조회로 얻은 경로 정보의 스냅샷입니다.
+#[derive(Debug, Clone)]
+pub struct CachedStat {
+    pub len: u64,
+    pub is_file: bool,
+    pub is_dir: bool,
+    pub readonly: bool,
+    pub modified: Option<SystemTime>,
+    pub accessed: Option<SystemTime>,
+    pub created: Option<SystemTime>,
+}
+
+impl From<&Metadata> for CachedStat {
+    fn from(metadata: &Metadata) -> Self {
+        CachedStat {
+            len: metadata.len(),
+            is_file: metadata.is_file(),
+            is_dir: metadata.is_dir(),
+            readonly: metadata.permissions().readonly(),
+            modified: metadata.modified().ok(),
+            accessed: metadata.accessed().ok(),
+            created: metadata.created().ok(),
+        }
+    }
+}
+
+// 디렉터리의 하위 항목을 상자 그리기 문자로 재귀적으로 기록합니다.
+// `show_sizes`가 `true`이면 일반 파일 이름 뒤에 크기를 덧붙입니다.
+fn write_tree(dir: &Path, prefix: &str, depth: usize, show_sizes: bool, output: &mut String) -> Result<()> {
+    if depth == 0 {
+        return Ok(());
+    }
+
+    let entries = read_dir_sorted(dir)?;
+    let count = entries.len();
+
+    for (index, entry) in entries.into_iter().enumerate() {
+        let is_last = index + 1 == count;
+        let branch = if is_last { "└── " } else { "├── " };
+        let file_type = entry.file_type()?;
+        output.push_str(&format!("{}{}{}", prefix, branch, entry.file_name().to_string_lossy()));
+        if show_sizes && file_type.is_file() {
+            output.push_str(&format!(" ({} bytes)", entry.metadata()?.len()));
+        }
+        output.push('\n');
+
+        if file_type.is_dir() {
+            let child_prefix = format!("{}{}", prefix, if is_last { "    " } else { "│   " });
+            write_tree(&entry.path(), &child_prefix, depth - 1, show_sizes, output)?;
+        }
+    }
+
+    Ok(())
+}
+
+// 디렉터리를 깊이 우선으로 순회하며 각 하위 항목에 대해 `visitor`를 호출합니다.
+// 심볼릭 링크 디렉터리는 순환을 피하기 위해 따라가지 않습니다.
+fn visit_entries<F: FnMut(&DirEntry) -> Result<()>>(dir: &Path, visitor: &mut F) -> Result<()> {
+    for entry in read_dir_sorted(dir)? {
+        visitor(&entry)?;
+
+        if entry.file_type()?.is_dir() {
+            visit_entries(&entry.path(), visitor)?;
+        }
+    }
+
+    Ok(())
+}
+
+// `visit_entries`와 같은 순서로 순회하되, 콜백이 `false`를 반환한 디렉터리는 내려가지 않습니다.
+fn visit_files_pruning<F: FnMut(&File) -> Result<bool>>(dir: &Path, callback: &mut F) -> Result<()> {
+    for entry in read_dir_sorted(dir)? {
+        let file = File::new(entry.path());
+        let descend = callback(&file)?;
+
+        if descend && entry.file_type()?.is_dir() {
+            visit_files_pruning(&file.path, callback)?;
+        }
+    }
+
+    Ok(())
+}
+
+// 하위 트리에 파일이 하나도 없으면 `true`를 반환하고, 비어 있는 디렉터리를 `empty_dirs`에 추가합니다.
+// 디렉터리가 아닌 항목(심볼릭 링크 포함)은 모두 내용으로 간주합니다.
+fn collect_empty_dirs(dir: &Path, empty_dirs: &mut Vec<File>) -> Result<bool> {
+    let mut is_empty = true;
+
+    for entry in read_dir_sorted(dir)? {
+        if entry.file_type()?.is_dir() {
+            let path = entry.path();
+            if collect_empty_dirs(&path, empty_dirs)? {
+                empty_dirs.push(File::new(path));
+            } else {
+                is_empty = false;
+            }
+        } else {
+            is_empty = false;
+        }
+    }
+
+    Ok(is_empty)
+}
+
+/// 파일에 대한 잠금을 보유하는 가드입니다.
+/// 가드가 해제될 때 잠금도 함께 풀립니다.
+pub struct FileLock {
+    handle: std::fs::File,
+}
+
+impl FileLock {
+    // 이미 열린 핸들에 배타적 잠금을 걸어 가드를 생성합니다.
+    fn exclusive(handle: std::fs::File) -> Result<FileLock> {
+        handle.lock()?;
+        Ok(FileLock { handle })
+    }
+
+    // 이미 열린 핸들에 공유 잠금을 걸어 가드를 생성합니다.
+    fn shared(handle: std::fs::File) -> Result<FileLock> {
+        handle.lock_shared()?;
+        Ok(FileLock { handle })
+    }
+
+    /// 잠금이 걸린 파일 핸들을 반환합니다.
+    pub fn handle(&self) -> &std::fs::File {
+        &self.handle
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = self.handle.unlock();
+    }
+}
+
+/// 여러 파일이 공유하는 가장 깊은 공통 상위 경로를 반환합니다.
+/// 파일 시스템에 접근하지 않고 경로를 구성 요소 단위로만 비교하며,
+/// 입력이 비어 있거나 공유하는 구성 요소가 없으면 `None`을 반환합니다.
+pub fn common_ancestor(files: &[File]) -> Option<File> {
+    let (first, rest) = files.split_first()?;
+    let mut common: Vec<_> = first.path.components().collect();
+
+    for file in rest {
+        let shared = common
+            .iter()
+            .zip(file.path.components())
+            .take_while(|(a, b)| *a == b)
+            .count();
+        common.truncate(shared);
+    }
+
+    if common.is_empty() {
+        None
+    } else {
+        Some(File::new(common.iter().collect::<PathBuf>()))
+    }
+}
+
+/// 파일을 고정 크기 단위로 지연 읽기하는 반복자입니다.
+/// `File::chunks`로 생성하며, 읽는 도중 발생한 오류는 `Err` 항목으로 전달됩니다.
+pub struct Chunks {
+    handle: Option<std::fs::File>,
+    size: usize,
+}
+
+impl Iterator for Chunks {
+    type Item = Result<Vec<u8>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let handle = self.handle.as_mut()?;
+        let mut chunk = Vec::with_capacity(self.size);
+
+        match handle.take(self.size as u64).read_to_end(&mut chunk) {
+            Ok(0) => {
+                self.handle = None;
+                None
+            }
+            Ok(_) => Some(Ok(chunk)),
+            Err(e) => {
+                self.handle = None;
+                Some(Err(e))
+            }
+        }
+    }
+}
+
+/// 두 체크섬 매니페스트 사이의 차이입니다.
+/// 각 목록은 루트 기준 상대 경로를 정렬된 순서로 담습니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ManifestDiff {
+    pub added: Vec<PathBuf>,
+    pub removed: Vec<PathBuf>,
+    pub changed: Vec<PathBuf>,
+}
+
+/// 디렉터리 아래 모든 파일의 상대 경로와 해시 값을 담은 매니페스트를 생성합니다.
+pub fn manifest(root: &File, algo: HashAlgorithm) -> Result<BTreeMap<PathBuf, String>> {
+    root.ensure_dir()?;
+
+    let mut entries = BTreeMap::new();
+    visit_entries(&root.path, &mut |entry| {
+        if entry.file_type()?.is_file() {
+            let path = entry.path();
+            let relative = path.strip_prefix(&root.path).unwrap_or(&path).to_path_buf();
+            entries.insert(relative, File::new(&path).hash_with(algo)?);
+        }
+        Ok(())
+    })?;
+
+    Ok(entries)
+}
+
+/// 두 매니페스트를 비교하여 추가, 삭제, 변경된 파일 목록을 반환합니다.
+pub fn diff_manifest(old: &BTreeMap<PathBuf, String>, new: &BTreeMap<PathBuf, String>) -> ManifestDiff {
+    let mut diff = ManifestDiff::default();
+
+    for (path, new_hash) in new {
+        match old.get(path) {
+            None => diff.added.push(path.clone()),
+            Some(old_hash) if old_hash != new_hash => diff.changed.push(path.clone()),
+            Some(_) => {}
+        }
+    }
+    diff.removed = old.keys().filter(|path| !new.contains_key(*path)).cloned().collect();
+
+    diff
 }
 
-impl File {
-    pub fn new<P: AsRef<Path>>(path: P) -> Self {
-        File {
-            path: path.as_ref().to_path_buf(),
+/// 디렉터리 트리를 한 번 순회하여 모은 통계입니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TreeStats {
+    pub file_count: usize,
+    pub dir_count: usize,
+    pub total_bytes: u64,
+    pub largest_file: Option<File>,
+}
+
+/// 내용이 같은 파일들의 묶음입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DuplicateGroup {
+    pub size: u64,
+    pub files: Vec<File>,
+}
+
+/// 중복 파일 검색 결과입니다.
+/// `wasted_bytes`는 각 묶음에서 하나만 남겼을 때 확보할 수 있는 크기의 합입니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DuplicateReport {
+    pub groups: Vec<DuplicateGroup>,
+    pub wasted_bytes: u64,
+}
+
+/// 두 디렉터리 트리를 비교한 결과입니다.
+/// 각 목록은 루트 기준 상대 경로를 정렬된 순서로 담습니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TreeReport {
+    pub only_in_self: Vec<PathBuf>,
+    pub only_in_other: Vec<PathBuf>,
+    pub content_differs: Vec<PathBuf>,
+}
+
+// 트리 아래 모든 파일의 상대 경로와 크기를 모읍니다.
+fn file_sizes(root: &Path) -> Result<BTreeMap<PathBuf, u64>> {
+    let mut sizes = BTreeMap::new();
+    visit_entries(root, &mut |entry| {
+        if entry.file_type()?.is_file() {
+            let path = entry.path();
+            let relative = path.strip_prefix(root).unwrap_or(&path).to_path_buf();
+            sizes.insert(relative, entry.metadata()?.len());
+        }
+        Ok(())
+    })?;
+    Ok(sizes)
+}
+
+// 이름 하나를 글롭 패턴과 비교합니다.
+// `*`는 0개 이상의 문자, `?`는 한 문자, `[abc]`/`[a-z]`/`[!a]`는 문자 집합과 일치합니다.
+fn glob_match(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    glob_match_chars(&pattern, &name)
+}
+
+fn glob_match_chars(pattern: &[char], name: &[char]) -> bool {
+    match pattern.first() {
+        None => name.is_empty(),
+        Some('*') => {
+            let rest = &pattern[1..];
+            (0..=name.len()).any(|skip| glob_match_chars(rest, &name[skip..]))
+        }
+        Some('?') => !name.is_empty() && glob_match_chars(&pattern[1..], &name[1..]),
+        Some('[') => match (name.first(), parse_glob_class(&pattern[1..])) {
+            (Some(c), Some(class)) => {
+                let in_class = class.ranges.iter().any(|(lo, hi)| lo <= c && c <= hi);
+                in_class != class.negated && glob_match_chars(&pattern[1 + class.len..], &name[1..])
+            }
+            // 닫는 괄호가 없으면 `[`를 일반 문자로 취급합니다.
+            (Some('['), None) => glob_match_chars(&pattern[1..], &name[1..]),
+            _ => false,
+        },
+        Some(c) => name.first() == Some(c) && glob_match_chars(&pattern[1..], &name[1..]),
+    }
+}
+
+// 글롭 패턴의 `[...]` 문자 집합입니다. `len`은 `[` 다음부터 `]`까지의 길이입니다.
+struct GlobClass {
+    ranges: Vec<(char, char)>,
+    negated: bool,
+    len: usize,
+}
+
+// `[` 다음부터 문자 집합을 해석하며, 닫는 괄호가 없으면 `None`을 반환합니다.
+fn parse_glob_class(pattern: &[char]) -> Option<GlobClass> {
+    let mut index = 0;
+    let negated = matches!(pattern.first(), Some('!') | Some('^'));
+    if negated {
+        index += 1;
+    }
+
+    let mut ranges = Vec::new();
+    let start = index;
+    while index < pattern.len() {
+        let c = pattern[index];
+        if c == ']' && index > start {
+            return Some(GlobClass { ranges, negated, len: index + 1 });
+        }
+
+        if index + 2 < pattern.len() && pattern[index + 1] == '-' && pattern[index + 2] != ']' {
+            ranges.push((c, pattern[index + 2]));
+            index += 3;
+        } else {
+            ranges.push((c, c));
+            index += 1;
+        }
+    }
+
+    None
+}
+
+// `root` 기준 상대 경로를 구성 요소 문자열 목록으로 반환합니다.
+fn relative_segments(root: &Path, path: &Path) -> Vec<String> {
+    path.strip_prefix(root)
+        .unwrap_or(path)
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect()
+}
+
+// `/`로 나뉜 패턴 구성 요소와 경로 구성 요소를 비교합니다.
+// `**` 구성 요소는 0개 이상의 디렉터리와 일치합니다.
+fn glob_match_segments(pattern: &[&str], path: &[String]) -> bool {
+    match pattern.first() {
+        None => path.is_empty(),
+        Some(&"**") => (0..=path.len()).any(|skip| glob_match_segments(&pattern[1..], &path[skip..])),
+        Some(segment) => {
+            !path.is_empty() && glob_match(segment, &path[0]) && glob_match_segments(&pattern[1..], &path[1..])
+        }
+    }
+}
+
+// 현재 프로세스의 유효 권한으로 경로에 쓰기(및 추가 권한)가 가능한지 확인합니다.
+#[cfg(unix)]
+fn access_writable(path: &Path, mode: libc::c_int) -> bool {
+    use std::os::unix::ffi::OsStrExt;
+
+    let Ok(c_path) = std::ffi::CString::new(path.as_os_str().as_bytes()) else {
+        return false;
+    };
+    // SAFETY: `c_path`는 NUL로 끝나는 유효한 문자열이며 호출 동안 살아 있습니다.
+    unsafe { libc::faccessat(libc::AT_FDCWD, c_path.as_ptr(), mode, libc::AT_EACCESS) == 0 }
+}
+
+// 디렉터리를 하위 항목까지 재귀적으로 복사합니다.
+// 파일을 가리키는 심볼릭 링크는 대상의 내용을 복사합니다.
+fn copy_dir_recursive(source: &Path, dest: &Path) -> Result<()> {
+    std::fs::create_dir_all(dest)?;
+
+    for entry in read_dir_sorted(source)? {
+        let target = dest.join(entry.file_name());
+        if entry.file_type()?.is_dir() {
+            copy_dir_recursive(&entry.path(), &target)?;
+        } else {
+            std::fs::copy(entry.path(), &target)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// `copy_tree_to`가 수행하는 개별 작업입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CopyAction {
+    /// 대상 디렉터리를 새로 만듭니다.
+    CreateDir(File),
+    /// 대상에 없는 파일을 새로 복사합니다.
+    Copy { from: File, to: File },
+    /// 크기나 수정 시간이 다른 기존 파일을 덮어씁니다.
+    Overwrite { from: File, to: File },
+    /// `target`을 가리키는 심볼릭 링크를 만들며, 대상 경로에 다른 항목이 있으면 교체합니다.
+    Symlink { from: File, to: File, target: PathBuf },
+    /// 크기와 수정 시간이 같거나 같은 대상을 가리키는 링크가 있어 복사를 건너뜁니다.
+    Skip { from: File, to: File },
+}
+
+/// 트리를 복사할 때 심볼릭 링크를 처리하는 방식입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SymlinkPolicy {
+    /// 링크를 따라가 대상의 내용을 복사합니다. 순환하는 링크는 `InvalidInput` 오류를 반환합니다.
+    Follow,
+    /// 같은 대상을 가리키는 링크를 새로 만듭니다.
+    Copy,
+    /// 링크를 복사하지 않습니다.
+    Skip,
+}
+
+// 원본 트리를 대상 경로와 비교하여 수행할 작업을 `actions`에 순서대로 추가합니다.
+// 디렉터리 생성 작업은 항상 그 하위 항목의 작업보다 먼저 추가되며,
+// `ancestors`에는 링크를 따라갈 때 순환을 찾기 위해 현재 경로까지의 정규화된 원본 디렉터리를 담습니다.
+fn plan_tree_copy(
+    source: &Path,
+    dest: &Path,
+    policy: SymlinkPolicy,
+    ancestors: &mut Vec<PathBuf>,
+    actions: &mut Vec<CopyAction>,
+) -> Result<()> {
+    match std::fs::metadata(dest) {
+        Ok(metadata) if !metadata.is_dir() => {
+            return Err(Error::new(
+                ErrorKind::NotADirectory,
+                format!("대상 경로가 디렉터리가 아닙니다: {}", dest.display()),
+            ));
+        }
+        Ok(_) => {}
+        Err(e) if e.kind() == ErrorKind::NotFound => actions.push(CopyAction::CreateDir(File::new(dest))),
+        Err(e) => return Err(e),
+    }
+
+    for entry in read_dir_sorted(source)? {
+        let path = entry.path();
+        let target = dest.join(entry.file_name());
+        let file_type = entry.file_type()?;
+
+        let is_dir = if file_type.is_symlink() {
+            match policy {
+                SymlinkPolicy::Skip => continue,
+                SymlinkPolicy::Copy => {
+                    actions.push(plan_symlink_copy(&path, &target)?);
+                    continue;
+                }
+                SymlinkPolicy::Follow => std::fs::metadata(&path)?.is_dir(),
+            }
+        } else {
+            file_type.is_dir()
+        };
+
+        if is_dir {
+            let canonical = std::fs::canonicalize(&path)?;
+            if ancestors.contains(&canonical) {
+                return Err(Error::new(
+                    ErrorKind::InvalidInput,
+                    format!("순환하는 심볼릭 링크입니다: {}", path.display()),
+                ));
+            }
+
+            ancestors.push(canonical);
+            plan_tree_copy(&path, &target, policy, ancestors, actions)?;
+            ancestors.pop();
+        } else {
+            actions.push(plan_file_copy(File::new(path), File::new(target))?);
+        }
+    }
+
+    Ok(())
+}
+
+// 파일 하나를 대상 경로로 복사하는 작업을 결정합니다.
+fn plan_file_copy(from: File, to: File) -> Result<CopyAction> {
+    match std::fs::metadata(&to.path) {
+        Ok(existing) if existing.is_dir() => Err(Error::new(
+            ErrorKind::IsADirectory,
+            format!("대상 경로가 디렉터리입니다: {}", to.path.display()),
+        )),
+        Ok(existing) => {
+            let source_metadata = from.metadata()?;
+            if existing.len() == source_metadata.len() && existing.modified()? == source_metadata.modified()? {
+                Ok(CopyAction::Skip { from, to })
+            } else {
+                Ok(CopyAction::Overwrite { from, to })
+            }
+        }
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(CopyAction::Copy { from, to }),
+        Err(e) => Err(e),
+    }
+}
+
+// 심볼릭 링크를 같은 대상을 가리키는 링크로 다시 만드는 작업을 결정합니다.
+fn plan_symlink_copy(link: &Path, dest: &Path) -> Result<CopyAction> {
+    let link_target = std::fs::read_link(link)?;
+    let (from, to) = (File::new(link), File::new(dest));
+
+    match std::fs::symlink_metadata(dest) {
+        Ok(existing) if existing.is_dir() => Err(Error::new(
+            ErrorKind::IsADirectory,
+            format!("대상 경로가 디렉터리입니다: {}", dest.display()),
+        )),
+        Ok(existing) if existing.file_type().is_symlink() && std::fs::read_link(dest)? == link_target => {
+            Ok(CopyAction::Skip { from, to })
+        }
+        Ok(_) => Ok(CopyAction::Symlink { from, to, target: link_target }),
+        Err(e) if e.kind() == ErrorKind::NotFound => Ok(CopyAction::Symlink { from, to, target: link_target }),
+        Err(e) => Err(e),
+    }
+}
+
+// 플랫폼에 맞는 방식으로 `link` 위치에 `target`을 가리키는 심볼릭 링크를 만듭니다.
+fn create_symlink(target: &Path, link: &Path) -> Result<()> {
+    #[cfg(unix)]
+    {
+        std::os::unix::fs::symlink(target, link)
+    }
+
+    #[cfg(windows)]
+    {
+        let resolved = link.parent().map(|parent| parent.join(target)).unwrap_or_else(|| target.to_path_buf());
+        if resolved.is_dir() {
+            std::os::windows::fs::symlink_dir(target, link)
+        } else {
+            std::os::windows::fs::symlink_file(target, link)
+        }
+    }
+
+    #[cfg(not(any(unix, windows)))]
+    {
+        let _ = (target, link);
+        Err(Error::new(ErrorKind::Unsupported, "이 플랫폼에서는 심볼릭 링크를 만들 수 없습니다"))
+    }
+}
+
+// `.`과 `..`를 파일 시스템 접근 없이 경로 문자열만으로 해석합니다.
+// 루트보다 위로 올라가는 `..`는 무시합니다.
+fn normalize_lexically(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+
+    for component in path.components() {
+        match component {
+            std::path::Component::CurDir => {}
+            std::path::Component::ParentDir => {
+                if !normalized.pop() {
+                    normalized.push(component);
+                }
+            }
+            other => normalized.push(other),
+        }
+    }
+
+    normalized
+}
+
+// 경로를 절대 경로로 해석합니다. 존재하는 가장 긴 앞부분은 운영체제가 여는 것과 같도록
+// 심볼릭 링크와 `..`까지 그대로 정규화하고, 존재하지 않는 나머지 부분만 어휘적으로 정리하여 덧붙입니다.
+// 앞부분을 먼저 어휘적으로 정리하면 `link/..`가 링크 대상의 상위 디렉터리가 아닌 곳으로 해석되므로 그렇게 하지 않습니다.
+fn resolve_path(path: &Path) -> Result<PathBuf> {
+    let absolute = std::path::absolute(path)?;
+    let components: Vec<_> = absolute.components().collect();
+
+    for split in (1..=components.len()).rev() {
+        let prefix: PathBuf = components[..split].iter().collect();
+        match prefix.canonicalize() {
+            Ok(mut resolved) => {
+                for component in &components[split..] {
+                    match component {
+                        std::path::Component::CurDir => {}
+                        std::path::Component::ParentDir => {
+                            resolved.pop();
+                        }
+                        other => resolved.push(other),
+                    }
+                }
+                return Ok(resolved);
+            }
+            Err(e) if e.kind() == ErrorKind::NotFound => {}
+            Err(e) => return Err(e),
+        }
+    }
+
+    Ok(normalize_lexically(&absolute))
+}
+
+/// 복사 대상 경로에 파일이 이미 존재할 때의 처리 방식입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverwritePolicy {
+    /// 기존 파일을 덮어씁니다.
+    Overwrite,
+    /// 복사하지 않고 기존 파일을 그대로 둡니다.
+    Skip,
+    /// `AlreadyExists` 오류를 반환합니다.
+    Error,
+    /// 확장자 앞에 ` (1)`, ` (2)` 등을 붙인 겹치지 않는 이름으로 복사합니다.
+    Rename,
+}
+
+// 확장자 앞에 ` (n)`을 붙인 경로를 반환합니다. 예: `foo.txt` → `foo (1).txt`
+fn numbered_path(path: &Path, n: usize) -> PathBuf {
+    let stem = path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+    let name = match path.extension() {
+        Some(ext) => format!("{} ({}).{}", stem, n, ext.to_string_lossy()),
+        None => format!("{} ({})", stem, n),
+    };
+    path.with_file_name(name)
+}
+
+// 번호를 붙인 이름을 찾을 때 시도하는 최대 번호입니다.
+const MAX_NUMBERED_NAMES: usize = 10_000;
+
+// 번호를 붙여도 비어 있는 이름을 찾지 못했을 때의 오류입니다.
+fn no_available_name(path: &Path) -> Error {
+    Error::new(
+        ErrorKind::AlreadyExists,
+        format!("사용 가능한 이름을 찾을 수 없습니다: {}", path.display()),
+    )
+}
+
+/// 고정 크기 바이트 배열로부터 만들 수 있는 레코드 타입입니다.
+/// 기본 정수 및 실수 타입은 리틀 엔디언으로 구현되어 있습니다.
+pub trait FromBytes: Sized {
+    /// 레코드 하나의 바이트 크기입니다.
+    const SIZE: usize;
+
+    /// 길이가 정확히 `SIZE`인 슬라이스로부터 값을 만듭니다.
+    fn from_bytes(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_from_bytes {
+    ($($ty:ty),*) => {
+        $(
+            impl FromBytes for $ty {
+                const SIZE: usize = std::mem::size_of::<$ty>();
+
+                fn from_bytes(bytes: &[u8]) -> Self {
+                    let mut array = [0u8; std::mem::size_of::<$ty>()];
+                    array.copy_from_slice(bytes);
+                    <$ty>::from_le_bytes(array)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_bytes!(u8, u16, u32, u64, u128, i8, i16, i32, i64, i128, f32, f64);
+
+/// 파일 목록의 순서와 이름에 관계없이 내용의 다중 집합에만 의존하는 해시 값을 반환합니다.
+/// 각 파일의 다이제스트를 정렬한 뒤 이어 붙여 다시 해싱합니다.
+pub fn set_hash(files: &[File], algo: HashAlgorithm) -> Result<String> {
+    let mut digests = files.iter().map(|file| file.digest(algo)).collect::<Result<Vec<_>>>()?;
+    digests.sort();
+
+    let mut hasher = Hasher::new(algo);
+    for digest in &digests {
+        hasher.update(digest);
+    }
+    Ok(hasher.finalize())
+}
+
+/// 임시 디렉터리를 보유하는 가드입니다.
+/// 가드가 해제될 때 디렉터리와 그 안의 내용을 모두 삭제합니다.
+pub struct TempDir {
+    dir: File,
+}
+
+impl TempDir {
+    /// 시스템 임시 디렉터리 아래에 고유한 이름의 디렉터리를 만들고 가드를 반환합니다.
+    pub fn new() -> Result<TempDir> {
+        Ok(TempDir { dir: File::temp_dir()? })
+    }
+
+    /// 임시 디렉터리를 가리키는 `File`을 반환합니다.
+    pub fn file(&self) -> &File {
+        &self.dir
+    }
+
+    /// 임시 디렉터리의 경로를 반환합니다.
+    pub fn path(&self) -> &Path {
+        &self.dir.path
+    }
+}
+
+impl Drop for TempDir {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_dir_all(&self.dir.path);
+    }
+}
+
+// 확장자와 MIME 타입의 대응표입니다. 확장자는 소문자로 적습니다.
+const MIME_TYPES: &[(&str, &str)] = &[
+    ("html", "text/html"),
+    ("htm", "text/html"),
+    ("css", "text/css"),
+    ("js", "text/javascript"),
+    ("mjs", "text/javascript"),
+    ("json", "application/json"),
+    ("xml", "application/xml"),
+    ("txt", "text/plain"),
+    ("csv", "text/csv"),
+    ("md", "text/markdown"),
+    ("png", "image/png"),
+    ("jpg", "image/jpeg"),
+    ("jpeg", "image/jpeg"),
+    ("gif", "image/gif"),
+    ("webp", "image/webp"),
+    ("svg", "image/svg+xml"),
+    ("ico", "image/vnd.microsoft.icon"),
+    ("avif", "image/avif"),
+    ("woff", "font/woff"),
+    ("woff2", "font/woff2"),
+    ("ttf", "font/ttf"),
+    ("otf", "font/otf"),
+    ("mp3", "audio/mpeg"),
+    ("wav", "audio/wav"),
+    ("ogg", "audio/ogg"),
+    ("mp4", "video/mp4"),
+    ("webm", "video/webm"),
+    ("pdf", "application/pdf"),
+    ("zip", "application/zip"),
+    ("gz", "application/gzip"),
+    ("tar", "application/x-tar"),
+    ("wasm", "application/wasm"),
+];
+
+/// 확장자로 MIME 타입을 찾아 반환합니다. 파일 내용은 확인하지 않습니다.
+/// 대소문자를 구분하지 않으며 앞의 `.`은 무시하고, 알 수 없는 확장자는 `None`을 반환합니다.
+pub fn mime_from_extension(ext: &str) -> Option<&'static str> {
+    let ext = ext.strip_prefix('.').unwrap_or(ext);
+    MIME_TYPES
+        .iter()
+        .find(|(known, _)| known.eq_ignore_ascii_case(ext))
+        .map(|&(_, mime)| mime)
+}
+
+/// 디렉터리 항목의 종류입니다. 심볼릭 링크는 따라가지 않고 링크 자체로 분류합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileKind {
+    File,
+    Dir,
+    Symlink,
+    Other,
+}
+
+impl From<std::fs::FileType> for FileKind {
+    fn from(file_type: std::fs::FileType) -> Self {
+        if file_type.is_symlink() {
+            FileKind::Symlink
+        } else if file_type.is_dir() {
+            FileKind::Dir
+        } else if file_type.is_file() {
+            FileKind::File
+        } else {
+            FileKind::Other
+        }
+    }
+}
+
+/// 파일에서 사용하는 줄바꿈 방식입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    /// 모든 줄바꿈이 `\n`입니다.
+    Lf,
+    /// 모든 줄바꿈이 `\r\n`입니다.
+    CrLf,
+    /// `\n`과 `\r\n`이 섞여 있습니다.
+    Mixed,
+    /// 줄바꿈이 없습니다.
+    None,
+}
+
+/// 파일 시스템의 전체, 남은, 사용 가능한 용량(바이트)입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DiskUsage {
+    pub total: u64,
+    /// 관리자 전용 예약 공간을 포함한 남은 용량입니다.
+    pub free: u64,
+    /// 현재 사용자가 실제로 쓸 수 있는 남은 용량입니다.
+    pub available: u64,
+}
+
+/// `File::compare`에서 사용하는 정렬 기준입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortKey {
+    /// 파일 이름의 사전순입니다. 파일 시스템에 접근하지 않습니다.
+    Name,
+    /// 파일 크기입니다.
+    Size,
+    /// 수정 시간입니다.
+    Modified,
+}
+
+/// `sync_to`의 결과로 복사, 건너뜀, 삭제한 파일 수입니다.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    pub copied: usize,
+    pub skipped: usize,
+    pub deleted: usize,
+}
+
+/// `File::identify`가 매직 바이트로 판별한 파일 형식입니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FileFormat {
+    Png,
+    Jpeg,
+    Gif,
+    Pdf,
+    Zip,
+    Gzip,
+    Elf,
+    /// 알려진 매직 바이트가 없고, NUL 없는 UTF-8로 해석되는 텍스트입니다.
+    PlainText,
+    Unknown,
+}
+
+// 형식별 매직 바이트입니다. 앞에서부터 차례로 비교합니다.
+const MAGIC_BYTES: &[(&[u8], FileFormat)] = &[
+    (b"\x89PNG\r\n\x1a\n", FileFormat::Png),
+    (b"\xff\xd8\xff", FileFormat::Jpeg),
+    (b"GIF87a", FileFormat::Gif),
+    (b"GIF89a", FileFormat::Gif),
+    (b"%PDF-", FileFormat::Pdf),
+    (b"PK\x03\x04", FileFormat::Zip),
+    (b"PK\x05\x06", FileFormat::Zip),
+    (b"PK\x07\x08", FileFormat::Zip),
+    (b"\x1f\x8b", FileFormat::Gzip),
+    (b"\x7fELF", FileFormat::Elf),
+];
+
+/// `File::metadata_diff`가 반환하는 두 파일의 메타데이터 차이입니다.
+/// 각 `..._differs` 값과 함께 `(자신, 상대)` 순서의 실제 값을 담습니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetadataDiff {
+    pub size_differs: bool,
+    pub modified_differs: bool,
+    pub permissions_differ: bool,
+    pub file_type_differs: bool,
+    pub sizes: (u64, u64),
+    pub modified: (SystemTime, SystemTime),
+    pub permissions: (std::fs::Permissions, std::fs::Permissions),
+    pub file_kinds: (FileKind, FileKind),
+}
+
+impl MetadataDiff {
+    /// 하나라도 다른 항목이 있으면 `true`를 반환합니다.
+    pub fn differs(&self) -> bool {
+        self.size_differs || self.modified_differs || self.permissions_differ || self.file_type_differs
+    }
+}
+
+/// `File::diff_lines`가 반환하는 줄 단위 변경 기록입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LineChange {
+    /// 두 파일에 모두 있는 줄입니다.
+    Unchanged(String),
+    /// 상대 파일에만 있는 줄입니다.
+    Added(String),
+    /// 자신에게만 있는 줄입니다.
+    Removed(String),
+}
+
+// 연속된 숫자를 수의 크기로 비교하는 자연 정렬 비교입니다. 예: `file2` < `file10`
+// 숫자 부분이 같은 값이면(`a01`, `a1`) 원래 문자열 순서로 결정합니다.
+fn natural_cmp(a: &str, b: &str) -> std::cmp::Ordering {
+    use std::cmp::Ordering;
+
+    let (mut left, mut right) = (a, b);
+    loop {
+        let (Some(l), Some(r)) = (left.chars().next(), right.chars().next()) else {
+            return left.len().cmp(&right.len()).then_with(|| a.cmp(b));
+        };
+
+        if l.is_ascii_digit() && r.is_ascii_digit() {
+            let l_len = left.find(|c: char| !c.is_ascii_digit()).unwrap_or(left.len());
+            let r_len = right.find(|c: char| !c.is_ascii_digit()).unwrap_or(right.len());
+            let l_num = left[..l_len].trim_start_matches('0');
+            let r_num = right[..r_len].trim_start_matches('0');
+
+            let ordering = l_num.len().cmp(&r_num.len()).then_with(|| l_num.cmp(r_num));
+            if ordering != Ordering::Equal {
+                return ordering;
+            }
+            left = &left[l_len..];
+            right = &right[r_len..];
+        } else {
+            if l != r {
+                return l.cmp(&r);
+            }
+            left = &left[l.len_utf8()..];
+            right = &right[r.len_utf8()..];
+        }
+    }
+}
+
+// 텍스트 비교를 위해 BOM을 제거하고 줄바꿈 방식과 각 줄 끝의 공백을 무시한 줄 목록을 만듭니다.
+fn normalized_text_lines(text: &str, ignore_final_newline: bool) -> Vec<&str> {
+    let text = text.strip_prefix('\u{feff}').unwrap_or(text);
+    let mut lines: Vec<&str> = text.split('\n').map(str::trim_end).collect();
+    if ignore_final_newline && lines.len() > 1 && lines.last() == Some(&"") {
+        lines.pop();
+    }
+    lines
+}
+
+/// 파일 시스템 경로를 다루는 핸들입니다.
+/// 동등성 비교, 해시, 정렬은 경로만을 기준으로 합니다.
+#[derive(Debug, Clone)]
+pub struct File {
+    path: PathBuf,
+    buffer_size: usize,
+}
+
+impl PartialEq for File {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path
+    }
+}
+
+impl Eq for File {}
+
+impl std::hash::Hash for File {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.path.hash(state);
+    }
+}
+
+impl PartialOrd for File {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for File {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.path.cmp(&other.path)
+    }
+}
+
+impl File {
+    pub fn new<P: AsRef<Path>>(path: P) -> Self {
+        File {
+            path: path.as_ref().to_path_buf(),
+            buffer_size: DEFAULT_BUFFER_SIZE,
+        }
+    }
+
+    /// 해싱, 복사 등 스트리밍 작업에서 사용할 버퍼 크기를 설정합니다.
+    /// 기본값은 64 KiB이며, 0을 지정하면 기본값을 사용합니다.
+    pub fn with_buffer_size(mut self, size: usize) -> Self {
+        self.buffer_size = if size == 0 { DEFAULT_BUFFER_SIZE } else { size };
+        self
+    }
+
+    /// 내부 경로를 반환합니다.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// 해당 경로의 메타데이터를 반환합니다.
+    /// 경로가 심볼릭 링크이면 링크가 가리키는 대상의 메타데이터를 반환합니다.
+    pub fn metadata(&self) -> Result<Metadata> {
+        metadata(&self.path)
+    }
+
+    /// 심볼릭 링크를 따라가지 않고 경로 자체의 메타데이터를 반환합니다.
+    /// 링크를 따라가면 안 되는 도구에서는 `metadata` 대신 이 함수를 사용합니다.
+    pub fn metadata_nofollow(&self) -> Result<Metadata> {
+        std::fs::symlink_metadata(&self.path)
+    }
+
+    /// 해당 경로의 파일 크기를 반환합니다.
+    /// metadata를 사용하므로 파일 혹은 디렉터리가 아니면 오류가 발생합니다.
+    pub fn len(&self) -> Result<u64> {
+        Ok(self.metadata()?.len())
+    }
+
+    /// 해당 경로의 파일 크기가 0인지 확인합니다.
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    /// 파일의 SHA-256 해시 값을 반환합니다.
+    /// 파일이 아니거나 오류가 발생하면 빈 문자열을 반환합니다.
+    pub fn hash(&self) -> String {
+        if !self.is_file() {
+            return String::new();
+        }
+
+        self.hash_with(HashAlgorithm::Sha256).unwrap_or_default()
+    }
+
+    /// 지정한 알고리즘으로 파일의 해시 값을 반환합니다.
+    /// 파일 전체를 메모리에 올리지 않고 버퍼 단위로 읽어 해싱합니다.
+    pub fn hash_with(&self, algo: HashAlgorithm) -> Result<String> {
+        Ok(to_hex(&self.digest(algo)?))
+    }
+
+    // 파일을 버퍼 단위로 읽어 원시 다이제스트 바이트를 계산합니다.
+    fn digest(&self, algo: HashAlgorithm) -> Result<Vec<u8>> {
+        digest_reader(std::fs::File::open(&self.path)?, algo, self.buffer_size)
+    }
+
+    /// 다른 파일과 해시 값을 비교하여 일치하는지 확인합니다.
+    /// 크기가 다르면 해싱하지 않고 바로 `false`를 반환합니다.
+    pub fn is_match(&self, other: &File) -> bool {
+        if !self.is_file() || !other.is_file() {
+            return false;
+        }
+
+        match (self.len(), other.len()) {
+            (Ok(a), Ok(b)) if a == b => {}
+            _ => return false,
+        }
+
+        match (self.digest(HashAlgorithm::Sha256), other.digest(HashAlgorithm::Sha256)) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    /// 스트림에서 읽은 내용과 SHA-256 해시 값을 비교하여 일치하는지 확인합니다.
+    pub fn is_match_reader<R: Read>(&self, reader: R) -> Result<bool> {
+        if !self.is_file() {
+            return Ok(false);
+        }
+
+        Ok(self.digest(HashAlgorithm::Sha256)? == digest_reader(reader, HashAlgorithm::Sha256, self.buffer_size)?)
+    }
+
+    /// 다른 파일과 Byte 단위로 비교하여 일치하는지 확인합니다.
+    pub fn is_deep_match(&self, other: &File) -> bool {
+        if !self.is_file() || !other.is_file() {
+            return false;
+        }
+
+        let self_content = std::fs::read(&self.path);
+        let other_content = std::fs::read(&other.path);
+
+        match (self_content, other_content) {
+            (Ok(a), Ok(b)) => a == b,
+            _ => false,
+        }
+    }
+
+    /// 경로가 파일을 가리키는지 확인합니다.
+    pub fn is_file(&self) -> bool {
+        self.path.is_file()
+    }
+
+    /// 경로가 디렉토리를 가리키는지 확인합니다.
+    pub fn is_directory(&self) -> bool {
+        self.path.is_dir()
+    }
+
+    /// 경로가 존재하는지 확인합니다.
+    pub fn exists(&self) -> bool {
+        self.path.exists()
+    }
+
+    /// 해당 경로의 파일 및 디렉터리를 삭제합니다.
+    pub fn rm(&self) -> Result<()> {
+        if self.is_file() {
+            std::fs::remove_file(&self.path)?;
+        } else if self.is_directory() {
+            std::fs::remove_dir_all(&self.path)?;
+        }
+
+        Ok(())
+    }
+
+    /// 파일만 삭제하며, 경로가 디렉터리이면 아무것도 삭제하지 않고 `IsADirectory` 오류를 반환합니다.
+    /// 심볼릭 링크는 대상이 아닌 링크 자체를 삭제합니다.
+    pub fn rm_file(&self) -> Result<()> {
+        if self.metadata_nofollow()?.is_dir() {
+            return Err(Error::new(
+                ErrorKind::IsADirectory,
+                format!("디렉터리는 rm_file로 삭제할 수 없습니다: {}", self.path.display()),
+            ));
+        }
+
+        std::fs::remove_file(&self.path)
+    }
+
+    /// 디렉터리를 하위 항목까지 모두 삭제하며, 경로가 디렉터리가 아니면 `NotADirectory` 오류를 반환합니다.
+    pub fn rm_dir(&self) -> Result<()> {
+        if !self.metadata_nofollow()?.is_dir() {
+            return Err(Error::new(
+                ErrorKind::NotADirectory,
+                format!("디렉터리가 아니므로 rm_dir로 삭제할 수 없습니다: {}", self.path.display()),
+            ));
+        }
+
+        std::fs::remove_dir_all(&self.path)
+    }
+
+    /// 메타데이터를 한 번만 조회하여 크기, 종류, 시간 정보를 담은 스냅샷을 반환합니다.
+    /// 여러 검사를 연달아 수행할 때 중복된 stat 호출을 줄일 수 있습니다.
+    pub fn stat_once(&self) -> Result<CachedStat> {
+        Ok(CachedStat::from(&self.metadata()?))
+    }
+
+    /// 같은 디렉터리 안에서 파일 이름만 변경하고 내부 경로를 갱신합니다.
+    /// 경로 구분자가 포함된 이름은 `InvalidInput` 오류를 반환합니다.
+    pub fn rename_to(&mut self, new_name: &str) -> Result<()> {
+        validate_file_name(new_name)?;
+
+        let new_path = self.path.with_file_name(new_name);
+        std::fs::rename(&self.path, &new_path)?;
+        self.path = new_path;
+
+        Ok(())
+    }
+
+    /// 경로가 존재하면서 파일인지 한 번의 메타데이터 조회로 확인합니다.
+    pub fn exists_as_file(&self) -> bool {
+        self.metadata().map(|m| m.is_file()).unwrap_or(false)
+    }
+
+    /// 경로가 존재하면서 디렉터리인지 한 번의 메타데이터 조회로 확인합니다.
+    pub fn exists_as_dir(&self) -> bool {
+        self.metadata().map(|m| m.is_dir()).unwrap_or(false)
+    }
+
+    /// 디렉터리 구조를 `tree` 명령과 비슷한 문자열로 반환합니다.
+    /// 항목은 이름순으로 정렬되며, 심볼릭 링크 디렉터리는 따라가지 않습니다.
+    pub fn tree_string(&self) -> Result<String> {
+        self.tree_string_depth(usize::MAX)
+    }
+
+    /// 최대 `max` 단계까지만 내려가는 디렉터리 구조 문자열을 반환합니다.
+    /// `max`가 0이면 루트 경로만 포함됩니다.
+    pub fn tree_string_depth(&self, max: usize) -> Result<String> {
+        self.tree_string_with(max, false)
+    }
+
+    /// `tree_string_depth`와 같지만, `show_sizes`가 `true`이면 파일 이름 뒤에 `(12 bytes)`처럼 크기를 덧붙입니다.
+    pub fn tree_string_with(&self, max: usize, show_sizes: bool) -> Result<String> {
+        let mut output = format!("{}\n", self.path.display());
+        write_tree(&self.path, "", max, show_sizes, &mut output)?;
+        Ok(output)
+    }
+
+    /// 파일을 대상 경로로 복사하고 복사된 파일을 반환합니다.
+    /// 대상 경로에 파일이 이미 존재하면 덮어씁니다.
+    pub fn copy_to<P: AsRef<Path>>(&self, dest: P) -> Result<File> {
+        std::fs::copy(&self.path, dest.as_ref())?;
+        Ok(File::new(dest))
+    }
+
+    /// 파일을 복사한 뒤 대상 파일의 수정 시간과 접근 시간을 원본과 동일하게 설정합니다.
+    pub fn copy_to_preserving<P: AsRef<Path>>(&self, dest: P) -> Result<File> {
+        let source_metadata = self.metadata()?;
+        let copied = self.copy_to(dest)?;
+
+        let times = FileTimes::new()
+            .set_accessed(source_metadata.accessed()?)
+            .set_modified(source_metadata.modified()?);
+        open_for_times(&copied.path)?.set_times(times)?;
+
+        Ok(copied)
+    }
+
+    /// 파일 크기가 `max_bytes` 이하일 때만 SHA-256 해시 값을 반환합니다.
+    /// 크기를 초과하면 해싱하지 않고 `FileTooLarge` 오류를 반환하며,
+    /// 확인 후 파일이 커지더라도 `max_bytes`를 넘겨 읽지 않습니다.
+    pub fn hash_limited(&self, max_bytes: u64) -> Result<String> {
+        let too_large = || {
+            Error::new(
+                ErrorKind::FileTooLarge,
+                format!("파일 크기가 허용된 최대 크기({} bytes)를 초과합니다", max_bytes),
+            )
+        };
+
+        let handle = std::fs::File::open(&self.path)?;
+        if handle.metadata()?.len() > max_bytes {
+            return Err(too_large());
+        }
+
+        let mut reader = handle.take(max_bytes.saturating_add(1));
+        let mut hasher = Hasher::new(HashAlgorithm::Sha256);
+        let mut buffer = vec![0u8; self.buffer_size];
+        let mut hashed = 0u64;
+        loop {
+            let read = match reader.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            hashed += read as u64;
+            if hashed > max_bytes {
+                return Err(too_large());
+            }
+            hasher.update(&buffer[..read]);
+        }
+
+        Ok(hasher.finalize())
+    }
+
+    // 디렉터리가 아니면 `NotADirectory` 오류를 반환합니다.
+    fn ensure_dir(&self) -> Result<()> {
+        if self.metadata()?.is_dir() {
+            Ok(())
+        } else {
+            Err(Error::new(
+                ErrorKind::NotADirectory,
+                format!("디렉터리가 아닙니다: {}", self.path.display()),
+            ))
+        }
+    }
+
+    /// 디렉터리 아래의 모든 파일 및 디렉터리를 깊이 우선 순서로 반환합니다.
+    /// 루트 자신은 포함하지 않으며, 심볼릭 링크 디렉터리는 따라가지 않습니다.
+    pub fn walk(&self) -> Result<Vec<File>> {
+        self.ensure_dir()?;
+
+        let mut entries = Vec::new();
+        visit_entries(&self.path, &mut |entry| {
+            entries.push(File::new(entry.path()));
+            Ok(())
+        })?;
+        Ok(entries)
+    }
+
+    /// 디렉터리 아래에서 수정 시간이 `[start, end]` 범위에 있는 파일을 반환합니다.
+    pub fn find_modified_between(&self, start: SystemTime, end: SystemTime) -> Result<Vec<File>> {
+        let mut found = Vec::new();
+
+        for entry in self.walk()? {
+            let metadata = entry.metadata()?;
+            if !metadata.is_file() {
+                continue;
+            }
+
+            let modified = metadata.modified()?;
+            if start <= modified && modified <= end {
+                found.push(entry);
+            }
+        }
+
+        Ok(found)
+    }
+
+    /// 디렉터리 아래에서 가장 큰 일반 파일 `n`개를 크기 내림차순으로 반환합니다.
+    /// 크기가 `n`인 힙만 유지하므로 트리 전체를 메모리에 모으지 않습니다.
+    pub fn largest(&self, n: usize) -> Result<Vec<File>> {
+        self.ensure_dir()?;
+
+        let mut heap = BinaryHeap::with_capacity(n + 1);
+        if n > 0 {
+            visit_entries(&self.path, &mut |entry| {
+                let metadata = entry.metadata()?;
+                if metadata.is_file() {
+                    heap.push(Reverse((metadata.len(), Reverse(entry.path()))));
+                    if heap.len() > n {
+                        heap.pop();
+                    }
+                }
+                Ok(())
+            })?;
+        }
+
+        Ok(heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|Reverse((_, Reverse(path)))| File::new(path))
+            .collect())
+    }
+
+    /// 디렉터리 아래에서 하위 트리에 파일이 하나도 없는 디렉터리를 경로순으로 반환합니다.
+    /// 비어 있는 디렉터리만 포함한 디렉터리도 비어 있는 것으로 간주하며, 루트 자신은 포함하지 않습니다.
+    pub fn find_empty_dirs(&self) -> Result<Vec<File>> {
+        self.ensure_dir()?;
+
+        let mut empty_dirs = Vec::new();
+        collect_empty_dirs(&self.path, &mut empty_dirs)?;
+        empty_dirs.sort();
+        Ok(empty_dirs)
+    }
+
+    // 경로의 마지막 구성 요소를 반환하고, 없으면 `InvalidInput` 오류를 반환합니다.
+    fn file_name_or_err(&self) -> Result<&OsStr> {
+        self.path.file_name().ok_or_else(|| {
+            Error::new(
+                ErrorKind::InvalidInput,
+                format!("파일 이름이 없는 경로입니다: {}", self.path.display()),
+            )
+        })
+    }
+
+    /// 파일 이름을 유지한 채 지정한 디렉터리 안으로 복사하고 복사된 파일을 반환합니다.
+    /// 디렉터리가 없으면 생성하며, 해당 경로가 디렉터리가 아닌 파일이면 오류를 반환합니다.
+    pub fn copy_into<P: AsRef<Path>>(&self, dir: P) -> Result<File> {
+        let dir = dir.as_ref();
+        let name = self.file_name_or_err()?;
+
+        if dir.exists() && !dir.is_dir() {
+            return Err(Error::new(
+                ErrorKind::NotADirectory,
+                format!("디렉터리가 아닙니다: {}", dir.display()),
+            ));
+        }
+        std::fs::create_dir_all(dir)?;
+
+        self.copy_to(dir.join(name))
+    }
+
+    /// 호출자가 구성한 `OpenOptions`로 해당 경로를 열어 원시 파일 핸들을 반환합니다.
+    pub fn open_with(&self, opts: &OpenOptions) -> Result<std::fs::File> {
+        opts.open(&self.path)
+    }
+
+    /// 읽기 전용으로 엽니다. 파일이 없으면 `NotFound` 오류를 반환합니다.
+    pub fn open_read(&self) -> Result<std::fs::File> {
+        self.open_with(OpenOptions::new().read(true))
+    }
+
+    /// 쓰기 전용으로 엽니다. 파일이 없으면 만들고, 있으면 내용을 비웁니다.
+    pub fn open_write(&self) -> Result<std::fs::File> {
+        self.open_with(OpenOptions::new().write(true).create(true).truncate(true))
+    }
+
+    /// 덧붙이기 모드로 엽니다. 파일이 없으면 만들고, 모든 쓰기는 파일 끝에 추가됩니다.
+    pub fn open_append(&self) -> Result<std::fs::File> {
+        self.open_with(OpenOptions::new().append(true).create(true))
+    }
+
+    /// 읽기와 쓰기가 모두 가능하도록 엽니다. 파일이 없으면 만들고, 기존 내용은 유지합니다.
+    pub fn open_rw(&self) -> Result<std::fs::File> {
+        self.open_with(OpenOptions::new().read(true).write(true).create(true).truncate(false))
+    }
+
+    /// 플랫폼과 관계없이 구분자를 `/`로 통일한 경로 문자열을 반환합니다.
+    /// 경로가 UTF-8이 아니면 `None`을 반환합니다.
+    pub fn to_slash(&self) -> Option<String> {
+        let path = self.path.to_str()?;
+        if MAIN_SEPARATOR == '/' {
+            Some(path.to_string())
+        } else {
+            Some(path.replace(MAIN_SEPARATOR, "/"))
+        }
+    }
+
+    /// `/`로 구분된 경로 문자열을 플랫폼 고유의 구분자로 변환하여 `File`을 생성합니다.
+    pub fn from_slash(s: &str) -> File {
+        if MAIN_SEPARATOR == '/' {
+            File::new(s)
+        } else {
+            File::new(s.replace('/', MAIN_SEPARATOR_STR))
+        }
+    }
+
+    /// 디렉터리 아래 파일 크기의 합계를 소문자 확장자별로 반환합니다.
+    /// 확장자가 없는 파일은 빈 문자열 키로 묶입니다.
+    pub fn usage_by_extension(&self) -> Result<HashMap<String, u64>> {
+        self.ensure_dir()?;
+
+        let mut usage = HashMap::new();
+        visit_entries(&self.path, &mut |entry| {
+            let metadata = entry.metadata()?;
+            if metadata.is_file() {
+                let extension = entry
+                    .path()
+                    .extension()
+                    .map(|ext| ext.to_string_lossy().to_lowercase())
+                    .unwrap_or_default();
+                *usage.entry(extension).or_insert(0) += metadata.len();
+            }
+            Ok(())
+        })?;
+
+        Ok(usage)
+    }
+
+    // 잠금용 핸들을 엽니다. 파일이 없으면 생성하지만 내용은 유지합니다.
+    fn open_for_lock(&self) -> Result<std::fs::File> {
+        OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(&self.path)
+    }
+
+    /// 파일에 배타적 잠금을 걸고 잠금 가드를 반환합니다.
+    /// 다른 프로세스나 스레드가 잠금을 보유 중이면 해제될 때까지 대기합니다.
+    pub fn lock(&self) -> Result<FileLock> {
+        FileLock::exclusive(self.open_for_lock()?)
+    }
+
+    /// 파일에 공유 잠금을 걸고 잠금 가드를 반환합니다.
+    pub fn lock_shared(&self) -> Result<FileLock> {
+        FileLock::shared(self.open_for_lock()?)
+    }
+
+    /// 배타적 잠금을 건 상태에서 파일 끝에 데이터를 추가합니다.
+    /// 여러 스레드나 프로세스가 동시에 추가해도 내용이 섞이지 않습니다.
+    pub fn append_locked<B: AsRef<[u8]>>(&self, data: B) -> Result<()> {
+        let handle = OpenOptions::new().append(true).create(true).open(&self.path)?;
+        let lock = FileLock::exclusive(handle)?;
+        lock.handle().write_all(data.as_ref())?;
+        lock.handle().flush()
+    }
+
+    /// 같은 디렉터리의 임시 파일에 내용을 기록한 뒤 대상 경로로 교체하여 원자적으로 씁니다.
+    /// 도중에 실패해도 기존 파일은 손상되지 않으며, 기존 파일의 권한은 유지됩니다.
+    pub fn write_atomic<B: AsRef<[u8]>>(&self, data: B) -> Result<()> {
+        let temp_path = temp_path_for(&self.path);
+
+        let result = (|| {
+            let mut handle = std::fs::File::create(&temp_path)?;
+            handle.write_all(data.as_ref())?;
+            if let Ok(existing) = self.metadata() {
+                handle.set_permissions(existing.permissions())?;
+            }
+            handle.sync_all()?;
+            std::fs::rename(&temp_path, &self.path)
+        })();
+
+        if result.is_err() {
+            let _ = std::fs::remove_file(&temp_path);
+        }
+        result
+    }
+
+    /// 파일을 새로 만들거나, 이미 존재하면 내용을 비운 뒤 데이터를 기록합니다.
+    /// 기존 내용이 사라지므로 보존이 필요하면 `create_new` 또는 `append`를 사용하세요.
+    pub fn create_or_truncate<B: AsRef<[u8]>>(&self, data: B) -> Result<()> {
+        let mut handle = OpenOptions::new().write(true).create(true).truncate(true).open(&self.path)?;
+        handle.write_all(data.as_ref())
+    }
+
+    /// 파일이 존재하지 않을 때만 새로 만들어 데이터를 기록합니다.
+    /// 이미 존재하면 기존 내용을 건드리지 않고 `AlreadyExists` 오류를 반환합니다.
+    pub fn create_new<B: AsRef<[u8]>>(&self, data: B) -> Result<()> {
+        let mut handle = OpenOptions::new().write(true).create_new(true).open(&self.path)?;
+        handle.write_all(data.as_ref())
+    }
+
+    /// 파일 끝에 데이터를 추가합니다. 파일이 없으면 새로 만듭니다.
+    /// 기존 내용은 그대로 유지됩니다.
+    pub fn append<B: AsRef<[u8]>>(&self, data: B) -> Result<()> {
+        let mut handle = OpenOptions::new().append(true).create(true).open(&self.path)?;
+        handle.write_all(data.as_ref())
+    }
+
+    /// `rm`을 수행하되 일시적인 `PermissionDenied` 오류나 Windows의 공유·잠금 위반 오류가 발생하면 재시도합니다.
+    /// Windows에서 백신 프로그램 등이 파일을 잠시 열고 있을 때 유용하며,
+    /// 재시도 간격은 `delay`부터 두 배씩 늘어나고 모두 실패하면 마지막 오류를 반환합니다.
+    pub fn rm_retry(&self, attempts: usize, delay: Duration) -> Result<()> {
+        let mut wait = delay;
+        let mut attempt = 1;
+
+        loop {
+            match self.rm() {
+                Err(e) if is_transient_lock_error(&e) && attempt < attempts => {
+                    std::thread::sleep(wait);
+                    wait = wait.saturating_mul(2);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// 파일의 내용을 다른 `File`로 스트리밍하여 덮어쓰고 복사한 바이트 수를 반환합니다.
+    /// 원본이 디렉터리이면 `IsADirectory` 오류를 반환합니다.
+    pub fn copy_contents_to(&self, dest: &File) -> Result<u64> {
+        if self.is_directory() {
+            return Err(Error::new(
+                ErrorKind::IsADirectory,
+                format!("디렉터리는 복사할 수 없습니다: {}", self.path.display()),
+            ));
+        }
+
+        let source = std::fs::File::open(&self.path)?;
+        let target = std::fs::File::create(&dest.path)?;
+        copy_stream(source, target, self.buffer_size)
+    }
+
+    /// 디렉터리 아래에서 같은 디렉터리 안에 대소문자만 다른 이름을 가진 항목들을 묶어 반환합니다.
+    /// 대소문자를 구분하지 않는 파일 시스템으로 옮기면 충돌하게 될 항목들입니다.
+    pub fn find_case_conflicts(&self) -> Result<Vec<Vec<File>>> {
+        self.ensure_dir()?;
+
+        let mut groups: BTreeMap<(PathBuf, String), Vec<File>> = BTreeMap::new();
+        visit_entries(&self.path, &mut |entry| {
+            let path = entry.path();
+            let parent = path.parent().map(Path::to_path_buf).unwrap_or_default();
+            let key = entry.file_name().to_string_lossy().to_lowercase();
+            groups.entry((parent, key)).or_default().push(File::new(path));
+            Ok(())
+        })?;
+
+        Ok(groups.into_values().filter(|group| group.len() > 1).collect())
+    }
+
+    /// 파일을 `size` 바이트 단위로 나누어 읽는 반복자를 반환합니다.
+    /// 마지막 조각은 `size`보다 작을 수 있으며, `size`가 0이면 `InvalidInput` 오류를 반환합니다.
+    pub fn chunks(&self, size: usize) -> Result<Chunks> {
+        if size == 0 {
+            return Err(Error::new(ErrorKind::InvalidInput, "조각 크기는 0보다 커야 합니다"));
+        }
+
+        Ok(Chunks {
+            handle: Some(std::fs::File::open(&self.path)?),
+            size,
+        })
+    }
+
+    /// 파일 전체를 UTF-8 문자열로 읽어 반환합니다.
+    /// 올바른 UTF-8이 아니면 `InvalidData` 오류를 반환합니다.
+    pub fn read_to_string(&self) -> Result<String> {
+        std::fs::read_to_string(&self.path)
+    }
+
+    /// 파일 전체를 읽어 줄 단위로 나눈 목록을 반환합니다.
+    /// 각 줄 끝의 `\r`은 제거되며, 파일 끝의 줄바꿈으로 생기는 마지막 빈 줄은 포함하지 않습니다.
+    pub fn read_lines(&self) -> Result<Vec<String>> {
+        let content = self.read_to_string()?;
+        let mut lines: Vec<String> = content
+            .split('\n')
+            .map(|line| line.strip_suffix('\r').unwrap_or(line).to_string())
+            .collect();
+
+        if content.is_empty() || content.ends_with('\n') {
+            lines.pop();
+        }
+
+        Ok(lines)
+    }
+
+    /// 두 경로가 같은 파일 시스템 객체를 가리키는지 확인합니다.
+    /// Unix에서는 `(dev, ino)`를, 그 외 플랫폼에서는 정규화된 경로를 비교합니다.
+    pub fn same_file(&self, other: &File) -> Result<bool> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+
+            let a = self.metadata()?;
+            let b = other.metadata()?;
+            Ok(a.dev() == b.dev() && a.ino() == b.ino())
+        }
+
+        #[cfg(not(unix))]
+        {
+            Ok(self.path.canonicalize()? == other.path.canonicalize()?)
+        }
+    }
+
+    /// 두 파일의 `offset` 위치부터 `len` 바이트가 같은지 확인합니다.
+    /// 파일 전체를 읽지 않고 해당 범위만 비교하며, 어느 한쪽이라도 범위가 파일 끝을 넘으면 `false`를 반환합니다.
+    pub fn bytes_equal_at(&self, other: &File, offset: u64, len: usize) -> Result<bool> {
+        let mut a = std::fs::File::open(&self.path)?;
+        let mut b = std::fs::File::open(&other.path)?;
+        a.seek(SeekFrom::Start(offset))?;
+        b.seek(SeekFrom::Start(offset))?;
+
+        let chunk = len.min(self.buffer_size);
+        let mut buffer_a = vec![0u8; chunk];
+        let mut buffer_b = vec![0u8; chunk];
+        let mut remaining = len;
+
+        while remaining > 0 {
+            let size = remaining.min(chunk);
+            for (handle, buffer) in [(&mut a, &mut buffer_a), (&mut b, &mut buffer_b)] {
+                match handle.read_exact(&mut buffer[..size]) {
+                    Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(false),
+                    result => result?,
+                }
+            }
+
+            if buffer_a[..size] != buffer_b[..size] {
+                return Ok(false);
+            }
+            remaining -= size;
+        }
+
+        Ok(true)
+    }
+
+    /// 파일 시스템이 권장하는 I/O 블록 크기를 반환합니다.
+    /// Unix에서는 `st_blksize`를, 그 외 플랫폼에서는 기본 버퍼 크기(64 KiB)를 반환합니다.
+    pub fn blocksize(&self) -> Result<u64> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            Ok(self.metadata()?.blksize())
+        }
+
+        #[cfg(not(unix))]
+        {
+            self.metadata()?;
+            Ok(DEFAULT_BUFFER_SIZE as u64)
+        }
+    }
+
+    /// 해당 경로까지의 디렉터리를 모두 생성합니다.
+    /// 권한은 프로세스의 umask를 따르며, 이미 존재하면 아무 작업도 하지 않습니다.
+    pub fn mkdirs(&self) -> Result<()> {
+        std::fs::create_dir_all(&self.path)
+    }
+
+    /// 지정한 권한 비트로 해당 경로까지의 디렉터리를 모두 생성합니다.
+    /// 새로 만들어지는 디렉터리에만 적용되며, 프로세스의 umask가 함께 적용됩니다.
+    #[cfg(unix)]
+    pub fn mkdirs_mode(&self, mode: u32) -> Result<()> {
+        use std::os::unix::fs::DirBuilderExt;
+
+        std::fs::DirBuilder::new().recursive(true).mode(mode).create(&self.path)
+    }
+
+    /// 파일의 첫 줄을 줄바꿈 문자 없이 반환하며, 빈 파일이면 `None`을 반환합니다.
+    /// 첫 줄바꿈까지만 읽으므로 큰 파일의 형식을 빠르게 판별할 때 유용합니다.
+    pub fn first_line(&self) -> Result<Option<String>> {
+        let mut reader = BufReader::new(std::fs::File::open(&self.path)?);
+        read_line_from(&mut reader)
+    }
+
+    /// 디렉터리 트리를 한 번만 순회하여 파일 수, 디렉터리 수, 전체 크기, 가장 큰 파일을 반환합니다.
+    /// 루트 자신은 디렉터리 수에 포함하지 않습니다.
+    pub fn tree_stats(&self) -> Result<TreeStats> {
+        self.ensure_dir()?;
+
+        let mut stats = TreeStats::default();
+        let mut largest_len = 0;
+        visit_entries(&self.path, &mut |entry| {
+            let file_type = entry.file_type()?;
+            if file_type.is_dir() {
+                stats.dir_count += 1;
+            } else if file_type.is_file() {
+                let len = entry.metadata()?.len();
+                stats.file_count += 1;
+                stats.total_bytes += len;
+                if stats.largest_file.is_none() || len > largest_len {
+                    largest_len = len;
+                    stats.largest_file = Some(File::new(entry.path()));
+                }
+            }
+            Ok(())
+        })?;
+
+        Ok(stats)
+    }
+
+    /// 파일의 `offset` 위치에서 `buf`를 가득 채울 만큼 읽습니다.
+    /// 파일 커서를 사용하지 않으므로 여러 스레드에서 동시에 위치 지정 읽기를 할 수 있습니다.
+    pub fn read_exact_at(&self, buf: &mut [u8], offset: u64) -> Result<()> {
+        let handle = std::fs::File::open(&self.path)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::FileExt;
+            handle.read_exact_at(buf, offset)
+        }
+
+        #[cfg(windows)]
+        {
+            use std::os::windows::fs::FileExt;
+
+            let mut filled = 0;
+            while filled < buf.len() {
+                match handle.seek_read(&mut buf[filled..], offset + filled as u64) {
+                    Ok(0) => return Err(Error::new(ErrorKind::UnexpectedEof, "파일 끝에 도달했습니다")),
+                    Ok(read) => filled += read,
+                    Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            Ok(())
+        }
+
+        #[cfg(not(any(unix, windows)))]
+        {
+            let _ = (handle, buf, offset);
+            Err(Error::new(ErrorKind::Unsupported, "이 플랫폼에서는 위치 지정 읽기를 할 수 없습니다"))
+        }
+    }
+
+    /// 파일의 `offset` 위치에 `buf` 전체를 기록합니다. 파일이 없으면 새로 만듭니다.
+    /// 파일 커서를 사용하지 않으며, 기존 내용 중 기록 범위 밖은 유지됩니다.
+    pub fn write_all_at(&self, buf: &[u8], offset: u64) -> Result<()> {
+        let handle = OpenOptions::new().write(true).create(true).truncate(false).open(&self.path)?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::FileExt;
+            handle.write_all_at(buf, offset)
+        }
+
+        #[cfg(windows)]
+        {
+            use std::os::windows::fs::FileExt;
+
+            let mut written = 0;
+            while written < buf.len() {
+                match handle.seek_write(&buf[written..], offset + written as u64) {
+                    Ok(0) => return Err(Error::new(ErrorKind::WriteZero, "데이터를 기록하지 못했습니다")),
+                    Ok(count) => written += count,
+                    Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                    Err(e) => return Err(e),
+                }
+            }
+            Ok(())
+        }
+
+        #[cfg(not(any(unix, windows)))]
+        {
+            let _ = (handle, buf, offset);
+            Err(Error::new(ErrorKind::Unsupported, "이 플랫폼에서는 위치 지정 쓰기를 할 수 없습니다"))
+        }
+    }
+
+    /// 디렉터리 아래에서 내용이 같은 파일들을 찾아 묶음과 낭비되는 용량을 반환합니다.
+    /// 크기로 먼저 후보를 추린 뒤 SHA-256 해시로 비교하며, 빈 파일은 제외합니다.
+    pub fn find_duplicates(&self) -> Result<DuplicateReport> {
+        self.ensure_dir()?;
+
+        let mut by_size: BTreeMap<u64, Vec<File>> = BTreeMap::new();
+        visit_entries(&self.path, &mut |entry| {
+            if entry.file_type()?.is_file() {
+                let len = entry.metadata()?.len();
+                if len > 0 {
+                    by_size.entry(len).or_default().push(File::new(entry.path()));
+                }
+            }
+            Ok(())
+        })?;
+
+        let mut report = DuplicateReport::default();
+        for (size, candidates) in by_size {
+            if candidates.len() < 2 {
+                continue;
+            }
+
+            let mut by_digest: BTreeMap<Vec<u8>, Vec<File>> = BTreeMap::new();
+            for file in candidates {
+                by_digest.entry(file.digest(HashAlgorithm::Sha256)?).or_default().push(file);
+            }
+
+            for (_, files) in by_digest {
+                if files.len() > 1 {
+                    report.wasted_bytes += (files.len() as u64 - 1) * size;
+                    report.groups.push(DuplicateGroup { size, files });
+                }
+            }
+        }
+
+        report.groups.sort_by(|a, b| a.files.cmp(&b.files));
+        Ok(report)
+    }
+
+    /// 디렉터리 바로 아래에서 이름이 글롭 패턴과 일치하는 항목을 이름순으로 반환합니다.
+    /// `*`, `?`, `[...]`를 지원하며 대소문자를 구분합니다.
+    pub fn glob(&self, pattern: &str) -> Result<Vec<File>> {
+        self.ensure_dir()?;
+
+        Ok(read_dir_sorted(&self.path)?
+            .into_iter()
+            .filter(|entry| glob_match(pattern, &entry.file_name().to_string_lossy()))
+            .map(|entry| File::new(entry.path()))
+            .collect())
+    }
+
+    /// 디렉터리 기준의 상대 경로가 글롭 패턴과 일치하는 항목을 트리 전체에서 찾아 반환합니다.
+    /// `glob`의 문법에 더해 `**` 구성 요소가 여러 단계의 디렉터리와 일치합니다(예: `src/**/*.rs`).
+    pub fn glob_recursive(&self, pattern: &str) -> Result<Vec<File>> {
+        self.ensure_dir()?;
+
+        let segments: Vec<&str> = pattern.split('/').filter(|s| !s.is_empty()).collect();
+        let mut matches = Vec::new();
+        visit_entries(&self.path, &mut |entry| {
+            let path = entry.path();
+            if glob_match_segments(&segments, &relative_segments(&self.path, &path)) {
+                matches.push(File::new(path));
+            }
+            Ok(())
+        })?;
+
+        Ok(matches)
+    }
+
+    /// 심볼릭 링크와 `.`, `..`를 해석한 절대 경로의 `File`을 반환합니다.
+    /// 경로가 존재하지 않으면 오류를 반환합니다.
+    pub fn canonicalize(&self) -> Result<File> {
+        Ok(File {
+            path: self.path.canonicalize()?,
+            buffer_size: self.buffer_size,
+        })
+    }
+
+    /// 정규화에 성공하면 정규화된 `File`을, 실패하면 원래 `File`을 그대로 반환합니다.
+    /// 로그 출력처럼 오류 처리 없이 최선의 정규화가 필요할 때 사용합니다.
+    pub fn canonical_or_self(&self) -> File {
+        self.canonicalize().unwrap_or_else(|_| self.clone())
+    }
+
+    /// 지정한 바이트 범위를 무시하고 두 파일을 바이트 단위로 비교합니다.
+    /// 한쪽에만 있는 바이트도 다른 것으로 간주하므로, 무시 범위 밖에서 길이가 다르면 `false`를 반환합니다.
+    pub fn is_match_ignoring(&self, other: &File, ranges: &[Range<u64>]) -> Result<bool> {
+        let mut a = std::fs::File::open(&self.path)?;
+        let mut b = std::fs::File::open(&other.path)?;
+        let mut buffer_a = vec![0u8; self.buffer_size];
+        let mut buffer_b = vec![0u8; self.buffer_size];
+        let mut offset = 0u64;
+
+        loop {
+            let read_a = read_full(&mut a, &mut buffer_a)?;
+            let read_b = read_full(&mut b, &mut buffer_b)?;
+            let longest = read_a.max(read_b);
+            if longest == 0 {
+                return Ok(true);
+            }
+
+            for index in 0..longest {
+                let byte_a = buffer_a[..read_a].get(index);
+                let byte_b = buffer_b[..read_b].get(index);
+                let position = offset + index as u64;
+
+                if byte_a != byte_b && !ranges.iter().any(|range| range.contains(&position)) {
+                    return Ok(false);
+                }
+            }
+            offset += longest as u64;
+        }
+    }
+
+    /// 파일의 수정 시간을 지정한 시각으로 설정합니다. 미래 시각도 허용됩니다.
+    pub fn set_modified(&self, time: SystemTime) -> Result<()> {
+        open_for_times(&self.path)?.set_modified(time)
+    }
+
+    /// 파일의 접근 시간과 수정 시간을 지정한 시각으로 설정합니다.
+    pub fn set_times(&self, accessed: SystemTime, modified: SystemTime) -> Result<()> {
+        open_for_times(&self.path)?.set_times(FileTimes::new().set_accessed(accessed).set_modified(modified))
+    }
+
+    /// 파일을 새로 만들거나 비운 뒤 `size` 바이트를 미리 할당하고 쓰기 가능한 핸들을 반환합니다.
+    /// Linux에서는 `fallocate`로 실제 블록을 예약하고, 지원하지 않으면 `set_len`으로 크기만 지정합니다.
+    pub fn create_preallocated(&self, size: u64) -> Result<std::fs::File> {
+        let handle = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&self.path)?;
+
+        // 길이가 0인 `fallocate`는 EINVAL로 실패하므로, 이미 비운 파일을 그대로 반환합니다.
+        if size == 0 {
+            return Ok(handle);
+        }
+
+        #[cfg(target_os = "linux")]
+        {
+            use std::os::fd::AsRawFd;
+
+            let len = libc::off_t::try_from(size).map_err(|e| Error::new(ErrorKind::InvalidInput, e))?;
+            // SAFETY: 유효한 파일 디스크립터에 대해 호출하며 포인터 인자를 사용하지 않습니다.
+            let result = unsafe { libc::fallocate(handle.as_raw_fd(), 0, 0, len) };
+            if result == 0 {
+                return Ok(handle);
+            }
+
+            // 파일 시스템이나 커널이 지원하지 않는 경우에만 `set_len`으로 대체합니다.
+            let err = Error::last_os_error();
+            if !matches!(err.raw_os_error(), Some(libc::EOPNOTSUPP | libc::ENOSYS | libc::EINVAL)) {
+                return Err(err);
+            }
+        }
+
+        handle.set_len(size)?;
+        Ok(handle)
+    }
+
+    /// 실제로 쓰지 않고 현재 프로세스가 해당 경로에 쓸 수 있는지 확인합니다.
+    /// 파일이 존재하면 파일의 권한을, 존재하지 않으면 상위 디렉터리에 파일을 만들 수 있는지를 확인합니다.
+    /// 검사와 실제 쓰기 사이에 권한이 바뀔 수 있으므로(TOCTOU), 쓰기 오류 처리를 대신하지는 못합니다.
+    pub fn is_writable(&self) -> bool {
+        let parent = match self.path.parent() {
+            Some(parent) if !parent.as_os_str().is_empty() => parent,
+            _ => Path::new("."),
+        };
+
+        #[cfg(unix)]
+        {
+            if self.path.exists() {
+                access_writable(&self.path, libc::W_OK)
+            } else {
+                parent.is_dir() && access_writable(parent, libc::W_OK | libc::X_OK)
+            }
+        }
+
+        #[cfg(not(unix))]
+        {
+            match self.metadata() {
+                Ok(metadata) => !metadata.permissions().readonly(),
+                Err(_) => metadata(parent).map(|m| m.is_dir() && !m.permissions().readonly()).unwrap_or(false),
+            }
+        }
+    }
+
+    /// `offset` 위치부터 `len` 바이트를 새 파일로 복사하고 복사된 파일을 반환합니다.
+    /// 범위가 파일 끝을 넘으면 남아 있는 바이트만 복사합니다.
+    pub fn copy_range_to<P: AsRef<Path>>(&self, offset: u64, len: u64, dest: P) -> Result<File> {
+        let mut source = std::fs::File::open(&self.path)?;
+        source.seek(SeekFrom::Start(offset))?;
+
+        let target = std::fs::File::create(dest.as_ref())?;
+        copy_stream(source.take(len), target, self.buffer_size)?;
+        Ok(File::new(dest))
+    }
+
+    /// 두 파일을 스트리밍으로 비교하여 처음으로 달라지는 바이트의 위치를 반환합니다.
+    /// 내용이 같으면 `None`을, 한쪽이 다른 쪽의 앞부분과 같으면 짧은 파일의 길이를 반환합니다.
+    pub fn first_difference(&self, other: &File) -> Result<Option<u64>> {
+        let mut a = std::fs::File::open(&self.path)?;
+        let mut b = std::fs::File::open(&other.path)?;
+        let mut buffer_a = vec![0u8; self.buffer_size];
+        let mut buffer_b = vec![0u8; self.buffer_size];
+        let mut offset = 0u64;
+
+        loop {
+            let read_a = read_full(&mut a, &mut buffer_a)?;
+            let read_b = read_full(&mut b, &mut buffer_b)?;
+            let shortest = read_a.min(read_b);
+
+            if let Some(index) = (0..shortest).find(|&i| buffer_a[i] != buffer_b[i]) {
+                return Ok(Some(offset + index as u64));
+            }
+            if read_a != read_b {
+                return Ok(Some(offset + shortest as u64));
+            }
+            if read_a == 0 {
+                return Ok(None);
+            }
+            offset += read_a as u64;
+        }
+    }
+
+    /// 파일 전체를 읽어 UTF-8로 해석하되, 잘못된 바이트 시퀀스는 대체 문자(U+FFFD)로 바꿔 반환합니다.
+    pub fn read_utf8_lossy(&self) -> Result<String> {
+        let content = std::fs::read(&self.path)?;
+        Ok(String::from_utf8(content).unwrap_or_else(|e| String::from_utf8_lossy(e.as_bytes()).into_owned()))
+    }
+
+    /// 파일 내용은 읽지 않고 트리의 정렬된 상대 경로 목록만으로 계산한 SHA-256 해시를 반환합니다.
+    /// 파일 추가, 삭제, 이름 변경은 감지하지만 내용 변경은 감지하지 않습니다.
+    pub fn structure_hash(&self) -> Result<String> {
+        self.ensure_dir()?;
+
+        let mut paths = Vec::new();
+        visit_entries(&self.path, &mut |entry| {
+            let path = entry.path();
+            let mut relative = relative_segments(&self.path, &path).join("/");
+            if entry.file_type()?.is_dir() {
+                relative.push('/');
+            }
+            paths.push(relative);
+            Ok(())
+        })?;
+        paths.sort();
+
+        let mut hasher = Hasher::new(HashAlgorithm::Sha256);
+        for path in &paths {
+            hasher.update(path.as_bytes());
+            hasher.update(b"\0");
+        }
+        Ok(hasher.finalize())
+    }
+
+    /// 파일 또는 디렉터리를 대상 경로로 이동하고 내부 경로를 갱신합니다.
+    /// 대상 경로가 이미 존재하면 덮어쓰지 않고 `AlreadyExists` 오류를 반환하며, 확인과 이동은 원자적으로 이루어집니다.
+    /// 서로 다른 파일 시스템 사이의 이동은 대상을 새로 만들어(이미 있으면 실패) 복사한 뒤 원본을 삭제합니다.
+    pub fn move_to<P: AsRef<Path>>(&mut self, dest: P) -> Result<()> {
+        let dest = dest.as_ref();
+        let already_exists = || {
+            Error::new(
+                ErrorKind::AlreadyExists,
+                format!("대상 경로가 이미 존재합니다: {}", dest.display()),
+            )
+        };
+
+        match rename_noreplace(&self.path, dest) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::AlreadyExists => return Err(already_exists()),
+            Err(e) if e.kind() == ErrorKind::CrossesDevices => {
+                if self.is_directory() {
+                    std::fs::create_dir(dest).map_err(|e| match e.kind() {
+                        ErrorKind::AlreadyExists => already_exists(),
+                        _ => e,
+                    })?;
+                    copy_dir_recursive(&self.path, dest)?;
+                } else {
+                    let target = OpenOptions::new().write(true).create_new(true).open(dest).map_err(|e| {
+                        match e.kind() {
+                            ErrorKind::AlreadyExists => already_exists(),
+                            _ => e,
+                        }
+                    })?;
+                    let permissions = self.metadata()?.permissions();
+                    copy_stream(std::fs::File::open(&self.path)?, &target, self.buffer_size)?;
+                    target.set_permissions(permissions)?;
+                }
+                self.rm()?;
+            }
+            Err(e) => return Err(e),
+        }
+
+        self.path = dest.to_path_buf();
+        Ok(())
+    }
+
+    /// `move_to`와 같지만, 대상 경로에 파일이 이미 존재하면 덮어씁니다.
+    /// 서로 다른 파일 시스템 사이의 이동은 복사 후 원본을 삭제하는 방식으로 처리합니다.
+    pub fn move_to_overwrite<P: AsRef<Path>>(&mut self, dest: P) -> Result<()> {
+        let dest = dest.as_ref();
+
+        match std::fs::rename(&self.path, dest) {
+            Ok(()) => {}
+            Err(e) if e.kind() == ErrorKind::CrossesDevices => {
+                if self.is_directory() {
+                    copy_dir_recursive(&self.path, dest)?;
+                } else {
+                    std::fs::copy(&self.path, dest)?;
+                }
+                self.rm()?;
+            }
+            Err(e) => return Err(e),
+        }
+
+        self.path = dest.to_path_buf();
+        Ok(())
+    }
+
+    /// 이름을 유지한 채 지정한 디렉터리 안으로 이동하고 내부 경로를 갱신합니다.
+    /// 디렉터리가 없으면 생성하며, 해당 경로가 디렉터리가 아닌 파일이면 오류를 반환합니다.
+    /// `move_to`와 마찬가지로 디렉터리 안에 같은 이름의 항목이 있으면 덮어쓰지 않고 `AlreadyExists` 오류를 반환합니다.
+    pub fn move_into<P: AsRef<Path>>(&mut self, dir: P) -> Result<()> {
+        let dir = dir.as_ref();
+        let name = self.file_name_or_err()?.to_os_string();
+
+        if dir.exists() && !dir.is_dir() {
+            return Err(Error::new(
+                ErrorKind::NotADirectory,
+                format!("디렉터리가 아닙니다: {}", dir.display()),
+            ));
+        }
+        std::fs::create_dir_all(dir)?;
+
+        self.move_to(dir.join(name))
+    }
+
+    /// 지정한 알고리즘으로 해시 값을 계산하여 대문자 또는 소문자 16진수 문자열로 반환합니다.
+    pub fn hash_with_case(&self, algo: HashAlgorithm, uppercase: bool) -> Result<String> {
+        let hex = self.hash_with(algo)?;
+        Ok(if uppercase { hex.to_uppercase() } else { hex })
+    }
+
+    /// 파일 전체를 바이트 배열로 읽어 반환합니다.
+    pub fn read_bytes(&self) -> Result<Vec<u8>> {
+        std::fs::read(&self.path)
+    }
+
+    /// 파일 전체를 읽되, 크기가 `max` 바이트를 넘으면 잘라내지 않고 `FileTooLarge` 오류를 반환합니다.
+    /// 읽는 도중 파일이 커지는 경우도 `max + 1` 바이트까지만 읽어 메모리 사용량을 제한합니다.
+    pub fn read_bytes_limited(&self, max: usize) -> Result<Vec<u8>> {
+        let too_large = || {
+            Error::new(
+                ErrorKind::FileTooLarge,
+                format!("파일 크기가 허용된 최대 크기({} bytes)를 초과합니다", max),
+            )
+        };
+
+        let handle = std::fs::File::open(&self.path)?;
+        let len = handle.metadata()?.len();
+        if len > max as u64 {
+            return Err(too_large());
+        }
+
+        let mut content = Vec::with_capacity(len as usize);
+        handle.take(max as u64 + 1).read_to_end(&mut content)?;
+        if content.len() > max {
+            return Err(too_large());
+        }
+
+        Ok(content)
+    }
+
+    /// 디렉터리 자체를 fsync하여 새로 만든 항목이 장애 후에도 남아 있도록 보장합니다.
+    /// `write_atomic` 이후 호출하면 교체된 이름까지 디스크에 기록됩니다.
+    /// Windows에서는 디렉터리 fsync를 지원하지 않으므로 디렉터리인지만 확인합니다.
+    pub fn sync_dir(&self) -> Result<()> {
+        self.ensure_dir()?;
+
+        #[cfg(unix)]
+        {
+            std::fs::File::open(&self.path)?.sync_all()
+        }
+
+        #[cfg(not(unix))]
+        {
+            Ok(())
+        }
+    }
+
+    /// 경로가 `root` 안에 포함되는지 확인합니다. `root` 자신도 포함된 것으로 간주합니다.
+    /// 존재하는 부분은 심볼릭 링크까지 정규화하고 나머지는 어휘적으로 해석하므로 `..` 탈출을 막을 수 있습니다.
+    pub fn is_inside(&self, root: &File) -> Result<bool> {
+        Ok(resolve_path(&self.path)?.starts_with(resolve_path(&root.path)?))
+    }
+
+    /// 대상 경로에 파일이 이미 존재할 때의 처리 방식을 지정하여 복사하고 결과 파일을 반환합니다.
+    /// `Skip`이면 기존 파일을, `Rename`이면 새로 정한 이름의 파일을 반환합니다.
+    pub fn copy_to_with_policy<P: AsRef<Path>>(&self, dest: P, policy: OverwritePolicy) -> Result<File> {
+        let dest = dest.as_ref();
+        match policy {
+            OverwritePolicy::Rename => self.copy_to_renamed(dest),
+            _ if !dest.exists() => self.copy_to(dest),
+            OverwritePolicy::Overwrite => self.copy_to(dest),
+            OverwritePolicy::Skip => Ok(File::new(dest)),
+            OverwritePolicy::Error => Err(Error::new(
+                ErrorKind::AlreadyExists,
+                format!("대상 경로에 파일이 이미 존재합니다: {}", dest.display()),
+            )),
+        }
+    }
+
+    // `dest`부터 ` (1)`, ` (2)` 등을 붙인 이름을 차례로 `create_new`로 만들어 처음 성공한 곳에 복사합니다.
+    // 이름을 고르는 것과 파일을 만드는 것이 한 번에 이루어지므로 동시에 호출해도 서로 덮어쓰지 않습니다.
+    fn copy_to_renamed(&self, dest: &Path) -> Result<File> {
+        let candidates = std::iter::once(dest.to_path_buf()).chain((1..=MAX_NUMBERED_NAMES).map(|n| numbered_path(dest, n)));
+
+        for candidate in candidates {
+            let target = match OpenOptions::new().write(true).create_new(true).open(&candidate) {
+                Ok(target) => target,
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e),
+            };
+
+            let result = (|| {
+                let source = std::fs::File::open(&self.path)?;
+                let permissions = source.metadata()?.permissions();
+                copy_stream(source, &target, self.buffer_size)?;
+                target.set_permissions(permissions)
+            })();
+            if let Err(e) = result {
+                let _ = std::fs::remove_file(&candidate);
+                return Err(e);
+            }
+            return Ok(File::new(candidate));
+        }
+
+        Err(no_available_name(dest))
+    }
+
+    /// `offset` 위치부터 고정 크기 레코드 `count`개를 읽어 반환합니다.
+    /// 파일에 남은 바이트가 레코드 `count`개보다 적으면 `UnexpectedEof` 오류를 반환합니다.
+    pub fn read_records<T: FromBytes>(&self, offset: u64, count: usize) -> Result<Vec<T>> {
+        let total = T::SIZE.checked_mul(count).ok_or_else(|| {
+            Error::new(ErrorKind::InvalidInput, "읽을 레코드의 전체 크기가 너무 큽니다")
+        })?;
+
+        let mut handle = std::fs::File::open(&self.path)?;
+        handle.seek(SeekFrom::Start(offset))?;
+
+        let mut buffer = vec![0u8; total];
+        let read = read_full(&mut handle, &mut buffer)?;
+        if read < total {
+            return Err(Error::new(
+                ErrorKind::UnexpectedEof,
+                format!(
+                    "레코드 {}개({} bytes)를 읽어야 하지만 {} bytes만 남아 있습니다",
+                    count, total, read
+                ),
+            ));
+        }
+
+        Ok(buffer.chunks_exact(T::SIZE).map(T::from_bytes).collect())
+    }
+
+    /// 현재 확장자가 `ext`와 다르면 `ext`를 덧붙인 새 `File`을 반환합니다.
+    /// `out`은 `out.png`가, `out.png`는 그대로, `out.jpg`는 `out.jpg.png`가 되며,
+    /// 앞의 `.`은 생략할 수 있고 확장자는 대소문자를 구분하지 않고 비교합니다.
+    /// `tar.gz`처럼 점이 여러 개인 확장자는 파일 이름이 `.tar.gz`로 끝나면 같은 것으로 봅니다.
+    pub fn ensure_extension(&self, ext: &str) -> File {
+        let ext = ext.trim_start_matches('.');
+        // `tar.gz`처럼 점이 여러 개인 확장자도 비교할 수 있도록 파일 이름 끝을 `.{ext}`와 비교합니다.
+        let suffix = format!(".{}", ext);
+        let matches = self
+            .path
+            .file_name()
+            .map(|name| {
+                let name = name.to_string_lossy();
+                name.len() > suffix.len()
+                    && name.as_bytes()[name.len() - suffix.len()..].eq_ignore_ascii_case(suffix.as_bytes())
+            })
+            .unwrap_or(false);
+
+        if matches || ext.is_empty() {
+            return self.clone();
+        }
+
+        let mut name = self.path.file_name().map(OsStr::to_os_string).unwrap_or_default();
+        name.push(".");
+        name.push(ext);
+        File {
+            path: self.path.with_file_name(name),
+            buffer_size: self.buffer_size,
+        }
+    }
+
+    /// 트리 아래의 모든 디렉터리를 경로순으로 반환합니다. 루트 자신은 포함하지 않습니다.
+    /// 순환을 피하기 위해 심볼릭 링크 디렉터리는 포함하지도, 따라가지도 않습니다.
+    pub fn all_dirs(&self) -> Result<Vec<File>> {
+        self.ensure_dir()?;
+
+        let mut dirs = Vec::new();
+        visit_entries(&self.path, &mut |entry| {
+            if entry.file_type()?.is_dir() {
+                dirs.push(File::new(entry.path()));
+            }
+            Ok(())
+        })?;
+
+        dirs.sort();
+        Ok(dirs)
+    }
+
+    // 메타데이터의 파일 종류에 대해 검사 함수를 적용하며, 조회에 실패하면 `false`를 반환합니다.
+    #[cfg(unix)]
+    fn file_type_is(&self, check: fn(&std::fs::FileType) -> bool) -> bool {
+        self.metadata().map(|m| check(&m.file_type())).unwrap_or(false)
+    }
+
+    /// 경로가 이름 있는 파이프(FIFO)를 가리키는지 확인합니다.
+    #[cfg(unix)]
+    pub fn is_fifo(&self) -> bool {
+        use std::os::unix::fs::FileTypeExt;
+        self.file_type_is(FileTypeExt::is_fifo)
+    }
+
+    /// 경로가 유닉스 도메인 소켓을 가리키는지 확인합니다.
+    #[cfg(unix)]
+    pub fn is_socket(&self) -> bool {
+        use std::os::unix::fs::FileTypeExt;
+        self.file_type_is(FileTypeExt::is_socket)
+    }
+
+    /// 경로가 문자 장치를 가리키는지 확인합니다.
+    #[cfg(unix)]
+    pub fn is_char_device(&self) -> bool {
+        use std::os::unix::fs::FileTypeExt;
+        self.file_type_is(FileTypeExt::is_char_device)
+    }
+
+    /// 경로가 블록 장치를 가리키는지 확인합니다.
+    #[cfg(unix)]
+    pub fn is_block_device(&self) -> bool {
+        use std::os::unix::fs::FileTypeExt;
+        self.file_type_is(FileTypeExt::is_block_device)
+    }
+
+    /// 파일 앞부분에서 최대 `k`줄을 줄바꿈 문자 없이 반환합니다.
+    /// `k`줄을 읽으면 즉시 멈추므로 큰 파일의 미리보기에 적합합니다.
+    pub fn head_lines(&self, k: usize) -> Result<Vec<String>> {
+        if k == 0 {
+            return Ok(Vec::new());
+        }
+
+        let file = std::fs::File::open(&self.path)?;
+        let mut reader = BufReader::with_capacity(self.buffer_size.min(DEFAULT_BUFFER_SIZE), file);
+        head_lines_from(&mut reader, k)
+    }
+
+    /// 파일 내용에서 `from`을 모두 `to`로 바꿔 원자적으로 저장하고 바꾼 횟수를 반환합니다.
+    /// 일치하는 부분이 없으면 파일을 다시 쓰지 않으므로 수정 시각도 그대로 유지됩니다.
+    pub fn replace(&self, from: &str, to: &str) -> Result<usize> {
+        if from.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidInput, "바꿀 문자열이 비어 있습니다"));
+        }
+
+        let content = self.read_to_string()?;
+        let count = content.matches(from).count();
+        if count == 0 {
+            return Ok(0);
+        }
+
+        self.write_atomic(content.replace(from, to))?;
+        Ok(count)
+    }
+
+    /// 같은 디렉터리의 `<이름>.<알고리즘>` 체크섬 파일에 적힌 해시와 파일의 해시를 비교합니다.
+    /// `<hex>  <이름>` 형식도 지원하며, 체크섬 파일이 없으면 `NotFound` 오류를 반환합니다.
+    pub fn verify_sidecar(&self, algo: HashAlgorithm) -> Result<bool> {
+        let mut sidecar_name = self.file_name_or_err()?.to_os_string();
+        sidecar_name.push(".");
+        sidecar_name.push(algo.extension());
+        let sidecar = self.path.with_file_name(sidecar_name);
+
+        let content = std::fs::read_to_string(&sidecar)?;
+        let expected = content
+            .split_whitespace()
+            .next()
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "체크섬 파일이 비어 있습니다"))?;
+
+        Ok(self.hash_with(algo)?.eq_ignore_ascii_case(expected))
+    }
+
+    /// `walk`와 같은 순서로 각 항목에 대해 콜백을 호출하며, 목록을 모아 두지 않습니다.
+    /// 콜백이 `Ok(false)`를 반환하면 해당 디렉터리의 하위 항목은 건너뛰고, `Err`를 반환하면 순회를 중단합니다.
+    pub fn walk_callback<F: FnMut(&File) -> Result<bool>>(&self, mut f: F) -> Result<()> {
+        self.ensure_dir()?;
+        visit_files_pruning(&self.path, &mut f)
+    }
+
+    /// 파일에 마지막 `n`줄만 남도록 원자적으로 다시 씁니다.
+    /// 파일 끝에서부터 버퍼 단위로 거꾸로 읽어 앞부분은 읽지 않습니다.
+    pub fn keep_last_lines(&self, n: usize) -> Result<()> {
+        let mut file = std::fs::File::open(&self.path)?;
+        let len = file.metadata()?.len();
+        if n == 0 {
+            return self.write_atomic([]);
+        }
+
+        let mut buffer = vec![0u8; self.buffer_size];
+        let mut end = len;
+        let mut start = 0;
+        let mut newlines = 0;
+        let mut skip_trailing = true;
+
+        'scan: while end > 0 {
+            let chunk = end.min(buffer.len() as u64) as usize;
+            let chunk_start = end - chunk as u64;
+            file.seek(SeekFrom::Start(chunk_start))?;
+            file.read_exact(&mut buffer[..chunk])?;
+
+            for i in (0..chunk).rev() {
+                if buffer[i] != b'\n' {
+                    skip_trailing = false;
+                    continue;
+                }
+                // 파일 끝의 줄바꿈은 새 줄의 시작이 아니므로 세지 않습니다.
+                if skip_trailing {
+                    skip_trailing = false;
+                    continue;
+                }
+                newlines += 1;
+                if newlines == n {
+                    start = chunk_start + i as u64 + 1;
+                    break 'scan;
+                }
+            }
+            end = chunk_start;
+        }
+
+        if start == 0 {
+            return Ok(());
+        }
+
+        let mut kept = Vec::with_capacity((len - start) as usize);
+        file.seek(SeekFrom::Start(start))?;
+        file.read_to_end(&mut kept)?;
+        self.write_atomic(kept)
+    }
+
+    /// Copy-on-Write 복제(Linux의 `FICLONE`, macOS의 `clonefile`)로 파일을 복사하고 복사된 파일을 반환합니다.
+    /// 복제를 지원하지 않는 환경에서는 `copy_to`와 같은 일반 복사로 대체합니다.
+    /// 사본은 임시 파일로 만든 뒤 대상 경로로 바꿔치기하므로 실패해도 기존 대상 파일은 남으며,
+    /// 원본 자신을 대상으로 지정하면 `InvalidInput` 오류를 반환합니다.
+    pub fn clone_to<P: AsRef<Path>>(&self, dest: P) -> Result<File> {
+        let dest = dest.as_ref();
+        if !self.is_file() {
+            return Err(Error::new(ErrorKind::InvalidInput, "일반 파일만 복제할 수 있습니다"));
+        }
+        if dest.exists() && self.same_file(&File::new(dest))? {
+            return Err(Error::new(ErrorKind::InvalidInput, "원본과 같은 파일로는 복제할 수 없습니다"));
+        }
+
+        // 기존 대상 파일은 교체할 사본이 완성된 뒤에만 바꿔치기합니다.
+        let temp_path = temp_path_for(dest);
+        let result = (|| {
+            if !try_reflink(&self.path, &temp_path)? {
+                std::fs::copy(&self.path, &temp_path)?;
+            }
+            std::fs::rename(&temp_path, dest)
+        })();
+
+        if result.is_err() {
+            let _ = std::fs::remove_file(&temp_path);
+        }
+        result.map(|()| File::new(dest))
+    }
+
+    /// 파일을 스트리밍으로 읽어 `needle`이 겹치지 않게 나타나는 횟수를 반환합니다.
+    /// 버퍼 경계에 걸친 일치도 올바르게 셉니다.
+    pub fn count_occurrences(&self, needle: &[u8]) -> Result<usize> {
+        if needle.is_empty() {
+            return Err(Error::new(ErrorKind::InvalidInput, "찾을 바이트열이 비어 있습니다"));
+        }
+
+        let mut file = std::fs::File::open(&self.path)?;
+        let mut buffer = vec![0u8; self.buffer_size];
+        let mut pending = Vec::new();
+        let mut count = 0;
+
+        loop {
+            let read = match file.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            pending.extend_from_slice(&buffer[..read]);
+
+            let mut i = 0;
+            while i + needle.len() <= pending.len() {
+                if pending[i..i + needle.len()] == *needle {
+                    count += 1;
+                    i += needle.len();
+                } else {
+                    i += 1;
+                }
+            }
+            // 다음 버퍼와 이어질 수 있는 `needle.len() - 1` 바이트 미만만 남깁니다.
+            pending.drain(..i);
+        }
+
+        Ok(count)
+    }
+
+    /// 디렉터리의 각 하위 항목 이름에 `f`를 적용해 `Some(새 이름)`이면 이름을 바꾸고 `None`이면 건너뜁니다.
+    /// 바꾸기 전에 대상 이름끼리의 충돌과 기존 항목과의 충돌을 모두 검사하여, 충돌이 있으면 아무것도 바꾸지 않고
+    /// `AlreadyExists` 오류를 반환합니다. UTF-8이 아닌 이름은 건너뛰며, 바뀐 `(이전, 이후)` 쌍을 반환합니다.
+    pub fn rename_children<F: Fn(&str) -> Option<String>>(&self, f: F) -> Result<Vec<(File, File)>> {
+        self.ensure_dir()?;
+
+        let mut planned = Vec::new();
+        let mut targets: HashMap<String, PathBuf> = HashMap::new();
+        for entry in read_dir_sorted(&self.path)? {
+            let file_name = entry.file_name();
+            let Some(name) = file_name.to_str() else {
+                continue;
+            };
+            let Some(new_name) = f(name).filter(|new_name| new_name != name) else {
+                continue;
+            };
+            validate_file_name(&new_name)?;
+
+            let source = File::new(entry.path());
+            let target = File::new(self.path.join(&new_name));
+            if let Some(previous) = targets.insert(new_name.clone(), source.path.clone()) {
+                return Err(Error::new(
+                    ErrorKind::AlreadyExists,
+                    format!("{}와 {}의 새 이름이 {}로 겹칩니다", previous.display(), source.path.display(), new_name),
+                ));
+            }
+            // 대소문자만 다른 이름으로 바꿀 때, 대소문자를 구분하지 않는 파일 시스템에서는 자기 자신이 보입니다.
+            if target.metadata_nofollow().is_ok() && !source.same_file(&target)? {
+                return Err(Error::new(
+                    ErrorKind::AlreadyExists,
+                    format!("대상 경로가 이미 존재합니다: {}", target.path.display()),
+                ));
+            }
+            planned.push((source, new_name));
+        }
+
+        let mut renamed = Vec::with_capacity(planned.len());
+        for (source, new_name) in planned {
+            let mut target = source.clone();
+            target.rename_to(&new_name)?;
+            renamed.push((source, target));
+        }
+        Ok(renamed)
+    }
+
+    /// 커널에 `offset`부터 `len` 바이트를 곧 순차적으로 읽을 것이라고 알려 페이지 캐시를 미리 채우게 합니다.
+    /// Linux 계열에서는 `posix_fadvise(POSIX_FADV_WILLNEED)`를 사용하며, 그 외 플랫폼에서는 아무 일도 하지 않습니다.
+    /// `len`이 0이면 `offset`부터 파일 끝까지를 뜻합니다.
+    pub fn readahead(&self, offset: u64, len: u64) -> Result<()> {
+        let handle = std::fs::File::open(&self.path)?;
+        if handle.metadata()?.is_dir() {
+            return Err(Error::new(ErrorKind::IsADirectory, "디렉터리에는 미리 읽기를 요청할 수 없습니다"));
+        }
+
+        #[cfg(any(target_os = "linux", target_os = "android", target_os = "freebsd"))]
+        {
+            use std::os::fd::AsRawFd;
+
+            let to_off = |value: u64| libc::off_t::try_from(value).map_err(|e| Error::new(ErrorKind::InvalidInput, e));
+            let (offset, len) = (to_off(offset)?, to_off(len)?);
+            // SAFETY: 유효한 파일 디스크립터에 대해 호출하며 포인터 인자를 사용하지 않습니다.
+            let result = unsafe { libc::posix_fadvise(handle.as_raw_fd(), offset, len, libc::POSIX_FADV_WILLNEED) };
+            // posix_fadvise는 errno 대신 오류 번호를 직접 반환합니다.
+            if result != 0 {
+                return Err(Error::from_raw_os_error(result));
+            }
+        }
+
+        #[cfg(not(any(target_os = "linux", target_os = "android", target_os = "freebsd")))]
+        let _ = (handle, offset, len);
+
+        Ok(())
+    }
+
+    /// 파일을 UTF-16LE로 해석하여 UTF-8 문자열로 반환합니다. 맨 앞의 BOM은 제거됩니다.
+    /// 바이트 수가 홀수이거나 잘못된 서로게이트가 있으면 `InvalidData` 오류를 반환합니다.
+    pub fn read_utf16le_to_string(&self) -> Result<String> {
+        decode_utf16(&self.read_bytes()?, false)
+    }
+
+    /// 파일을 UTF-16BE로 해석하여 UTF-8 문자열로 반환합니다. 맨 앞의 BOM은 제거됩니다.
+    /// 바이트 수가 홀수이거나 잘못된 서로게이트가 있으면 `InvalidData` 오류를 반환합니다.
+    pub fn read_utf16be_to_string(&self) -> Result<String> {
+        decode_utf16(&self.read_bytes()?, true)
+    }
+
+    /// 시스템 임시 디렉터리 아래에 무작위 이름의 디렉터리를 새로 만들어 반환합니다.
+    /// 자동으로 삭제되지 않으므로, 범위를 벗어날 때 정리하려면 `TempDir`을 사용합니다.
+    pub fn temp_dir() -> Result<File> {
+        let base = std::env::temp_dir();
+        for _ in 0..16 {
+            let path = base.join(format!("retrotv-{:016x}", random_u64()));
+            match std::fs::create_dir(&path) {
+                Ok(()) => return Ok(File::new(path)),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => continue,
+                Err(e) => return Err(e),
+            }
+        }
+
+        Err(Error::new(ErrorKind::AlreadyExists, "고유한 임시 디렉터리 이름을 만들지 못했습니다"))
+    }
+
+    /// 파일 내용을 스트리밍으로 읽어 비암호학적 64비트 FNV-1a 해시를 계산합니다.
+    /// SHA-256보다 빠르지만 충돌이 생길 수 있으므로 중복 후보를 거르는 용도로만 사용해야 합니다.
+    pub fn fast_fingerprint(&self) -> Result<u64> {
+        const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+        const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+        let mut file = std::fs::File::open(&self.path)?;
+        let mut buffer = vec![0u8; self.buffer_size];
+        let mut hash = FNV_OFFSET;
+
+        loop {
+            let read = match file.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            for &byte in &buffer[..read] {
+                hash ^= u64::from(byte);
+                hash = hash.wrapping_mul(FNV_PRIME);
+            }
+        }
+
+        Ok(hash)
+    }
+
+    /// 크기를 먼저 비교한 뒤 `fast_fingerprint` 값으로 두 파일이 같은지 판단합니다.
+    /// 충돌 가능성이 있으므로 `true`인 경우 필요하면 `is_match`나 `is_deep_match`로 다시 확인해야 합니다.
+    pub fn fast_match(&self, other: &File) -> Result<bool> {
+        if self.len()? != other.len()? {
+            return Ok(false);
+        }
+
+        Ok(self.fast_fingerprint()? == other.fast_fingerprint()?)
+    }
+
+    /// 디렉터리의 직계 하위 항목을 종류와 함께 이름순으로 반환합니다.
+    /// `DirEntry::file_type`을 사용하므로 운영체제가 지원하면 항목마다 추가 stat 호출이 없습니다.
+    pub fn entries_with_type(&self) -> Result<Vec<(File, FileKind)>> {
+        self.ensure_dir()?;
+
+        read_dir_sorted(&self.path)?
+            .into_iter()
+            .map(|entry| Ok((File::new(entry.path()), FileKind::from(entry.file_type()?))))
+            .collect()
+    }
+
+    /// `copy_tree_to`가 수행할 작업 목록을 대상 경로를 건드리지 않고 반환합니다.
+    /// 대상에 크기와 수정 시간이 같은 파일이 있으면 `Skip`, 다르면 `Overwrite`로 분류합니다.
+    pub fn copy_tree_plan<P: AsRef<Path>>(&self, dest: P) -> Result<Vec<CopyAction>> {
+        self.copy_tree_plan_with(dest, SymlinkPolicy::Follow)
+    }
+
+    /// `copy_tree_plan`과 같지만, 심볼릭 링크를 `policy`에 따라 처리한 작업 목록을 반환합니다.
+    pub fn copy_tree_plan_with<P: AsRef<Path>>(&self, dest: P, policy: SymlinkPolicy) -> Result<Vec<CopyAction>> {
+        self.ensure_dir()?;
+
+        let mut ancestors = vec![std::fs::canonicalize(&self.path)?];
+        let mut actions = Vec::new();
+        plan_tree_copy(&self.path, dest.as_ref(), policy, &mut ancestors, &mut actions)?;
+        Ok(actions)
+    }
+
+    /// 디렉터리 트리를 대상 경로로 복사하고 수행한 작업 목록을 반환합니다.
+    /// 복사한 파일은 원본의 수정 시간을 유지하므로, 다시 실행하면 바뀐 파일만 복사합니다.
+    /// 심볼릭 링크는 따라가서 내용을 복사합니다(`SymlinkPolicy::Follow`).
+    pub fn copy_tree_to<P: AsRef<Path>>(&self, dest: P) -> Result<Vec<CopyAction>> {
+        self.copy_tree_to_with(dest, SymlinkPolicy::Follow)
+    }
+
+    /// `copy_tree_to`와 같지만, 심볼릭 링크를 `policy`에 따라 따라가거나, 링크로 다시 만들거나, 건너뜁니다.
+    pub fn copy_tree_to_with<P: AsRef<Path>>(&self, dest: P, policy: SymlinkPolicy) -> Result<Vec<CopyAction>> {
+        let actions = self.copy_tree_plan_with(dest, policy)?;
+
+        for action in &actions {
+            match action {
+                CopyAction::CreateDir(dir) => std::fs::create_dir(&dir.path)?,
+                CopyAction::Copy { from, to } | CopyAction::Overwrite { from, to } => {
+                    from.copy_to_preserving(&to.path)?;
+                }
+                CopyAction::Symlink { to, target, .. } => {
+                    if std::fs::symlink_metadata(&to.path).is_ok() {
+                        std::fs::remove_file(&to.path)?;
+                    }
+                    create_symlink(target, &to.path)?;
+                }
+                CopyAction::Skip { .. } => {}
+            }
+        }
+
+        Ok(actions)
+    }
+
+    /// 파일의 수정 시간이 현재로부터 `max_age` 이내이면 내용을 `Some`으로, 오래되었으면 `None`을 반환합니다.
+    /// 수정 시간이 미래인 경우는 최신으로 간주하며, 파일이 없으면 `NotFound` 오류를 반환합니다.
+    pub fn read_bytes_if_fresh(&self, max_age: Duration) -> Result<Option<Vec<u8>>> {
+        let modified = self.metadata()?.modified()?;
+        let age = SystemTime::now().duration_since(modified).unwrap_or(Duration::ZERO);
+        if age > max_age {
+            return Ok(None);
+        }
+
+        self.read_bytes().map(Some)
+    }
+
+    /// 파일을 스트리밍으로 검사하여 줄바꿈 방식을 반환합니다.
+    /// `\n` 없이 단독으로 쓰인 `\r`은 줄바꿈으로 보지 않습니다.
+    pub fn line_ending(&self) -> Result<LineEnding> {
+        let mut file = std::fs::File::open(&self.path)?;
+        let mut buffer = vec![0u8; self.buffer_size];
+        let (mut lf, mut crlf) = (false, false);
+        let mut previous = 0u8;
+
+        loop {
+            let read = match file.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+
+            for &byte in &buffer[..read] {
+                // 버퍼 경계에 걸친 `\r\n`도 처리하도록 직전 바이트를 유지합니다.
+                if byte == b'\n' {
+                    if previous == b'\r' {
+                        crlf = true;
+                    } else {
+                        lf = true;
+                    }
+                }
+                previous = byte;
+            }
+            if lf && crlf {
+                return Ok(LineEnding::Mixed);
+            }
+        }
+
+        Ok(match (lf, crlf) {
+            (true, true) => LineEnding::Mixed,
+            (true, false) => LineEnding::Lf,
+            (false, true) => LineEnding::CrLf,
+            (false, false) => LineEnding::None,
+        })
+    }
+
+    /// 같은 디렉터리에 임시 심볼릭 링크를 만든 뒤 현재 경로 위로 이름을 바꿔, 링크 대상을 원자적으로 교체합니다.
+    /// 배포의 `current` 링크처럼 교체 도중 링크가 잠시라도 사라지면 안 되는 경우에 사용합니다.
+    #[cfg(unix)]
+    pub fn symlink_to_atomic<P: AsRef<Path>>(&self, target: P) -> Result<()> {
+        let temp_path = temp_path_for(&self.path);
+        std::os::unix::fs::symlink(target, &temp_path)?;
+
+        std::fs::rename(&temp_path, &self.path).inspect_err(|_| {
+            let _ = std::fs::remove_file(&temp_path);
+        })
+    }
+
+    /// 같은 디렉터리 안에서 원래 확장자를 유지하는 무작위 이름의 `File`을 반환하며, 디스크에는 아무것도 만들지 않습니다.
+    /// 같은 파일 시스템에 있으므로 나중에 현재 경로 위로 이름을 바꾸는 작업이 원자적으로 이루어집니다.
+    pub fn sibling_temp(&self) -> File {
+        let stem = self.path.file_stem().map(|s| s.to_string_lossy().into_owned()).unwrap_or_default();
+        let name = match self.path.extension() {
+            Some(ext) => format!(".{}.{:016x}.{}", stem, random_u64(), ext.to_string_lossy()),
+            None => format!(".{}.{:016x}", stem, random_u64()),
+        };
+
+        File::new(self.path.with_file_name(name)).with_buffer_size(self.buffer_size)
+    }
+
+    /// 경로가 속한 파일 시스템의 전체, 남은, 사용 가능한 용량을 반환합니다.
+    /// Unix에서는 `statvfs`를, Windows에서는 `GetDiskFreeSpaceExW`를 사용합니다.
+    #[allow(clippy::unnecessary_cast)]
+    pub fn disk_usage(&self) -> Result<DiskUsage> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::ffi::OsStrExt;
+
+            let path = std::ffi::CString::new(self.path.as_os_str().as_bytes())
+                .map_err(|e| Error::new(ErrorKind::InvalidInput, e))?;
+            let mut stat = std::mem::MaybeUninit::<libc::statvfs>::uninit();
+            // SAFETY: NUL로 끝나는 경로와 statvfs 구조체 크기의 버퍼를 전달합니다.
+            if unsafe { libc::statvfs(path.as_ptr(), stat.as_mut_ptr()) } != 0 {
+                return Err(Error::last_os_error());
+            }
+            // SAFETY: statvfs가 성공했으므로 구조체가 초기화되었습니다.
+            let stat = unsafe { stat.assume_init() };
+
+            // 필드 타입이 플랫폼마다 달라 u64로 맞춥니다.
+            let fragment = stat.f_frsize as u64;
+            Ok(DiskUsage {
+                total: stat.f_blocks as u64 * fragment,
+                free: stat.f_bfree as u64 * fragment,
+                available: stat.f_bavail as u64 * fragment,
+            })
+        }
+
+        #[cfg(windows)]
+        {
+            use std::os::windows::ffi::OsStrExt;
+
+            // GetDiskFreeSpaceExW는 디렉터리 경로를 받으므로 파일이면 상위 디렉터리를 사용합니다.
+            let dir = if self.metadata()?.is_dir() {
+                self.path.as_path()
+            } else {
+                self.path.parent().unwrap_or(&self.path)
+            };
+            let wide: Vec<u16> = dir.as_os_str().encode_wide().chain(std::iter::once(0)).collect();
+            let (mut available, mut total, mut free) = (0u64, 0u64, 0u64);
+            // SAFETY: NUL로 끝나는 UTF-16 경로와 유효한 u64 포인터를 전달합니다.
+            let ok = unsafe {
+                windows_sys::Win32::Storage::FileSystem::GetDiskFreeSpaceExW(
+                    wide.as_ptr(),
+                    &mut available,
+                    &mut total,
+                    &mut free,
+                )
+            };
+            if ok == 0 {
+                return Err(Error::last_os_error());
+            }
+
+            Ok(DiskUsage { total, free, available })
+        }
+
+        #[cfg(not(any(unix, windows)))]
+        {
+            Err(Error::new(ErrorKind::Unsupported, "이 플랫폼에서는 디스크 용량을 조회할 수 없습니다"))
+        }
+    }
+
+    /// 두 파일을 `key` 기준으로 비교합니다.
+    /// `Name`은 경로만으로 비교하고, `Size`와 `Modified`는 메타데이터를 조회합니다.
+    pub fn compare(&self, other: &File, key: SortKey) -> Result<std::cmp::Ordering> {
+        Ok(match key {
+            SortKey::Name => self.path.file_name().cmp(&other.path.file_name()),
+            SortKey::Size => self.len()?.cmp(&other.len()?),
+            SortKey::Modified => self.metadata()?.modified()?.cmp(&other.metadata()?.modified()?),
+        })
+    }
+
+    /// Reader의 내용을 하나의 버퍼로 스트리밍하여 파일에 기록하고 기록한 바이트 수를 반환합니다.
+    /// 파일이 있으면 내용을 비우고, 상위 디렉터리가 없으면 함께 만듭니다.
+    pub fn write_from<R: Read>(&self, reader: R) -> Result<u64> {
+        if let Some(parent) = self.path.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+            std::fs::create_dir_all(parent)?;
+        }
+
+        let handle = OpenOptions::new().write(true).create(true).truncate(true).open(&self.path)?;
+        copy_stream(reader, handle, self.buffer_size)
+    }
+
+    /// 트리에서 내용이 같은 파일을 찾아, 묶음마다 첫 번째 파일만 남기고 나머지를 그 파일의 하드 링크로 바꿉니다.
+    /// 해시가 같더라도 링크하기 전에 바이트 단위로 다시 비교하며, 새로 링크한 파일 수를 반환합니다.
+    /// 같은 묶음의 파일은 하나의 내용과 권한을 공유하게 되고, 이미 같은 파일을 가리키면 건너뜁니다.
+    pub fn dedup_with_hardlinks(&self) -> Result<usize> {
+        let mut linked = 0;
+
+        for group in self.find_duplicates()?.groups {
+            let Some((canonical, duplicates)) = group.files.split_first() else {
+                continue;
+            };
+
+            for duplicate in duplicates {
+                if canonical.same_file(duplicate)? || canonical.first_difference(duplicate)?.is_some() {
+                    continue;
+                }
+
+                // Windows에서는 읽기 전용 파일 위로 이름을 바꿀 수 없으므로 속성을 먼저 해제합니다.
+                #[cfg(windows)]
+                {
+                    let mut permissions = duplicate.metadata()?.permissions();
+                    if permissions.readonly() {
+                        #[allow(clippy::permissions_set_readonly_false)]
+                        permissions.set_readonly(false);
+                        std::fs::set_permissions(&duplicate.path, permissions)?;
+                    }
+                }
+
+                // 임시 링크를 만든 뒤 이름을 바꿔, 실패해도 원래 파일이 사라지지 않게 합니다.
+                let temp_path = temp_path_for(&duplicate.path);
+                std::fs::hard_link(&canonical.path, &temp_path)?;
+                std::fs::rename(&temp_path, &duplicate.path).inspect_err(|_| {
+                    let _ = std::fs::remove_file(&temp_path);
+                })?;
+                linked += 1;
+            }
+        }
+
+        Ok(linked)
+    }
+
+    /// `read_bytes_limited`처럼 최대 `max` 바이트까지만 읽어 UTF-8 문자열로 반환합니다.
+    /// 크기를 초과하면 `FileTooLarge`, UTF-8이 아니면 `InvalidData` 오류를 반환합니다.
+    pub fn read_to_string_limited(&self, max: usize) -> Result<String> {
+        String::from_utf8(self.read_bytes_limited(max)?).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+
+    /// 실행 권한을 켜거나 끕니다. Unix에서는 읽기 권한이 있는 대상(소유자, 그룹, 기타)에만 `x` 비트를 켜고,
+    /// 끌 때는 모든 `x` 비트를 지웁니다. Windows에는 실행 권한 비트가 없으므로 아무 일도 하지 않고 `Ok(())`를 반환합니다.
+    pub fn set_executable(&self, exec: bool) -> Result<()> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let mut permissions = self.metadata()?.permissions();
+            let mode = permissions.mode();
+            let mode = if exec { mode | ((mode & 0o444) >> 2) } else { mode & !0o111 };
+            permissions.set_mode(mode);
+            std::fs::set_permissions(&self.path, permissions)
+        }
+
+        #[cfg(not(unix))]
+        {
+            let _ = exec;
+            self.metadata().map(|_| ())
+        }
+    }
+
+    /// 파일을 실행할 수 있는지 확인합니다.
+    /// Unix에서는 `x` 비트 중 하나라도 켜져 있는지, Windows에서는 확장자가 `exe`, `com`, `bat`, `cmd`인지 확인합니다.
+    pub fn is_executable(&self) -> Result<bool> {
+        let metadata = self.metadata()?;
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            Ok(metadata.is_file() && metadata.permissions().mode() & 0o111 != 0)
+        }
+
+        #[cfg(not(unix))]
+        {
+            let is_program = self.path.extension().is_some_and(|ext| {
+                ["exe", "com", "bat", "cmd"].iter().any(|known| ext.eq_ignore_ascii_case(known))
+            });
+            Ok(metadata.is_file() && is_program)
+        }
+    }
+
+    /// 크기, `fast_fingerprint`, SHA-256 순으로 두 파일을 비교합니다.
+    /// 앞 단계에서 다르다고 판단되면 바로 `false`를 반환하므로, 비싼 해싱은 빠른 지문까지 같을 때만 수행합니다.
+    pub fn is_match_tiered(&self, other: &File) -> Result<bool> {
+        if !self.fast_match(other)? {
+            return Ok(false);
+        }
+
+        Ok(self.digest(HashAlgorithm::Sha256)? == other.digest(HashAlgorithm::Sha256)?)
+    }
+
+    /// 원본 트리의 파일 중 대상에 없거나 SHA-256 해시가 다른 파일만 대상 경로로 복사합니다.
+    /// 대상에만 있는 파일은 그대로 두며, 삭제하려면 `sync_to_with`를 사용합니다.
+    pub fn sync_to<P: AsRef<Path>>(&self, dest: P) -> Result<SyncReport> {
+        self.sync_to_with(dest, false)
+    }
+
+    /// `sync_to`와 같지만, `delete_extraneous`가 `true`이면 원본에 없는 대상의 파일을 삭제합니다.
+    /// 삭제는 파일 단위로만 이루어지며 비게 된 디렉터리는 남겨 둡니다.
+    pub fn sync_to_with<P: AsRef<Path>>(&self, dest: P, delete_extraneous: bool) -> Result<SyncReport> {
+        let dest = File::new(dest);
+        let source_manifest = manifest(self, HashAlgorithm::Sha256)?;
+        let dest_manifest = if dest.exists() { manifest(&dest, HashAlgorithm::Sha256)? } else { BTreeMap::new() };
+
+        let mut report = SyncReport::default();
+        for (relative, hash) in &source_manifest {
+            if dest_manifest.get(relative) == Some(hash) {
+                report.skipped += 1;
+                continue;
+            }
+
+            let target = dest.path.join(relative);
+            if let Some(parent) = target.parent() {
+                std::fs::create_dir_all(parent)?;
+            }
+            std::fs::copy(self.path.join(relative), &target)?;
+            report.copied += 1;
+        }
+
+        if delete_extraneous {
+            for relative in dest_manifest.keys().filter(|relative| !source_manifest.contains_key(*relative)) {
+                std::fs::remove_file(dest.path.join(relative))?;
+                report.deleted += 1;
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// 파일을 스트리밍으로 읽어 바이트별 빈도로 계산한 섀넌 엔트로피(바이트당 비트, 0~8)를 반환합니다.
+    /// 8에 가까우면 압축되었거나 암호화된 데이터일 가능성이 높으며, 빈 파일은 0을 반환합니다.
+    pub fn entropy(&self) -> Result<f64> {
+        let mut file = std::fs::File::open(&self.path)?;
+        let mut buffer = vec![0u8; self.buffer_size];
+        let mut histogram = [0u64; 256];
+        let mut total = 0u64;
+
+        loop {
+            let read = match file.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            for &byte in &buffer[..read] {
+                histogram[byte as usize] += 1;
+            }
+            total += read as u64;
+        }
+
+        if total == 0 {
+            return Ok(0.0);
+        }
+
+        let total = total as f64;
+        Ok(histogram
+            .iter()
+            .filter(|&&count| count > 0)
+            .map(|&count| {
+                let p = count as f64 / total;
+                -p * p.log2()
+            })
+            .sum())
+    }
+
+    /// 파일을 새로 만들거나 비운 뒤 데이터를 기록하고, 같은 핸들로 수정 시간을 `modified`로 설정합니다.
+    /// 수정 시간에 따라 동작이 달라지는 코드를 테스트할 때 픽스처를 만드는 용도입니다.
+    pub fn create_with_times<B: AsRef<[u8]>>(&self, data: B, modified: SystemTime) -> Result<()> {
+        let mut handle = OpenOptions::new().write(true).create(true).truncate(true).open(&self.path)?;
+        handle.write_all(data.as_ref())?;
+        // 기록이 끝난 뒤 설정해야 쓰기로 인해 수정 시간이 다시 바뀌지 않습니다.
+        handle.set_modified(modified)
+    }
+
+    /// 자기 자신부터 상위 디렉터리, 그 상위 디렉터리 순으로 루트까지 올라가며 `File`을 반환합니다.
+    /// 경로 문자열만으로 계산하며, 상대 경로는 맨 앞 구성 요소에서 끝납니다.
+    pub fn ancestors(&self) -> impl Iterator<Item = File> + '_ {
+        self.path
+            .ancestors()
+            .filter(|ancestor| !ancestor.as_os_str().is_empty())
+            .map(File::new)
+    }
+
+    /// 상위 디렉터리로 올라가며 `name`이라는 항목을 가진 가장 가까운 디렉터리를 찾아 반환합니다.
+    /// 자신이 파일이면 그 파일이 있는 디렉터리부터, 아니면 자기 자신부터 찾으며, 루트까지 없으면 `None`을 반환합니다.
+    /// 상대 경로는 현재 디렉터리 기준의 절대 경로로 바꾼 뒤 찾으므로, 반환되는 경로는 항상 절대 경로입니다.
+    pub fn find_upward(&self, name: &str) -> Option<File> {
+        let absolute = File::new(std::path::absolute(&self.path).ok()?);
+        let start = if absolute.is_file() { File::new(absolute.path.parent()?) } else { absolute };
+
+        start
+            .ancestors()
+            .find(|dir| std::fs::symlink_metadata(dir.path.join(name)).is_ok())
+    }
+
+    /// 파일을 복사한 뒤 원본과 대상의 SHA-256 해시를 비교하여 검증된 복사본을 반환합니다.
+    /// 해시가 다르면 손상된 대상 파일을 삭제하고 `InvalidData` 오류를 반환합니다.
+    pub fn copy_to_verified<P: AsRef<Path>>(&self, dest: P) -> Result<File> {
+        let copied = self.copy_to(dest)?;
+
+        if self.digest(HashAlgorithm::Sha256)? != copied.digest(HashAlgorithm::Sha256)? {
+            let _ = std::fs::remove_file(&copied.path);
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("복사한 파일의 해시가 원본과 다릅니다: {}", copied.path.display()),
+            ));
+        }
+
+        Ok(copied)
+    }
+
+    /// 호출자가 제공한 버퍼를 비우고 파일 전체를 읽어 넣은 뒤 읽은 바이트 수를 반환합니다.
+    /// 버퍼의 용량을 재사용하므로 여러 파일을 차례로 읽을 때 할당을 줄일 수 있습니다.
+    pub fn read_into(&self, buf: &mut Vec<u8>) -> Result<usize> {
+        buf.clear();
+
+        let mut handle = std::fs::File::open(&self.path)?;
+        let len = handle.metadata()?.len();
+        buf.reserve(usize::try_from(len).unwrap_or(0));
+        handle.read_to_end(buf)
+    }
+
+    /// 디렉터리를 읽지 않고 파일 이름(전체 경로가 아님)이 글롭 패턴과 일치하는지 확인합니다.
+    /// `glob`과 같은 문법과 대소문자 규칙을 따르며, 이름이 없는 경로는 `false`를 반환합니다.
+    pub fn matches_glob(&self, pattern: &str) -> bool {
+        self.path
+            .file_name()
+            .is_some_and(|name| glob_match(pattern, &name.to_string_lossy()))
+    }
+
+    /// 원본을 한 번만 읽으면서 대상 경로로 복사하고 동시에 해시를 계산하여, 복사된 파일과 16진수 해시를 반환합니다.
+    /// `copy_to`처럼 대상 파일이 있으면 덮어쓰고 원본의 권한을 복사합니다.
+    pub fn copy_to_hashing<P: AsRef<Path>>(&self, dest: P, algo: HashAlgorithm) -> Result<(File, String)> {
+        let mut source = std::fs::File::open(&self.path)?;
+        let permissions = source.metadata()?.permissions();
+        let mut target = OpenOptions::new().write(true).create(true).truncate(true).open(dest.as_ref())?;
+
+        let mut hasher = Hasher::new(algo);
+        let mut buffer = vec![0u8; self.buffer_size];
+        loop {
+            let read = match source.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            hasher.update(&buffer[..read]);
+            target.write_all(&buffer[..read])?;
+        }
+
+        target.flush()?;
+        target.set_permissions(permissions)?;
+        Ok((File::new(dest), hasher.finalize()))
+    }
+
+    /// `write_atomic`처럼 원자적으로 쓰되, 비밀 정보를 담기 위해 소유자만 읽고 쓸 수 있는 권한으로 만듭니다.
+    /// Unix에서는 임시 파일을 처음부터 `0o600`으로 만들고, 기존 파일이 있으면 그 권한과 `0o600`의 교집합을 사용해
+    /// 권한이 넓어지지 않게 합니다. Windows에서는 상위 디렉터리의 기본 ACL을 그대로 사용합니다.
+    pub fn write_secure<B: AsRef<[u8]>>(&self, data: B) -> Result<()> {
+        let temp_path = temp_path_for(&self.path);
+
+        let result = (|| {
+            let mut options = OpenOptions::new();
+            options.write(true).create_new(true);
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::OpenOptionsExt;
+                options.mode(0o600);
+            }
+
+            let mut handle = options.open(&temp_path)?;
+            handle.write_all(data.as_ref())?;
+            #[cfg(unix)]
+            {
+                use std::os::unix::fs::PermissionsExt;
+
+                // umask와 관계없이 최종 권한이 0o600을 넘지 않도록 명시적으로 설정합니다.
+                let mode = match self.metadata() {
+                    Ok(existing) => existing.permissions().mode() & 0o600,
+                    Err(_) => 0o600,
+                };
+                handle.set_permissions(std::fs::Permissions::from_mode(mode))?;
+            }
+            handle.sync_all()?;
+            std::fs::rename(&temp_path, &self.path)
+        })();
+
+        if result.is_err() {
+            let _ = std::fs::remove_file(&temp_path);
+        }
+        result
+    }
+
+    /// 디렉터리 아래의 모든 항목을 너비 우선 순서로 반환합니다. 같은 깊이의 항목은 이름순입니다.
+    /// `walk`와 마찬가지로 루트 자신은 포함하지 않고, 심볼릭 링크 디렉터리는 따라가지 않아 순환하지 않습니다.
+    pub fn walk_breadth_first(&self) -> Result<Vec<File>> {
+        self.ensure_dir()?;
+
+        let mut entries = Vec::new();
+        let mut pending = std::collections::VecDeque::from([self.path.clone()]);
+        while let Some(dir) = pending.pop_front() {
+            for entry in read_dir_sorted(&dir)? {
+                if entry.file_type()?.is_dir() {
+                    pending.push_back(entry.path());
+                }
+                entries.push(File::new(entry.path()));
+            }
+        }
+
+        Ok(entries)
+    }
+
+    /// 파일의 `(크기, 수정 시간)`이 캐시에 저장된 값과 같으면 저장된 SHA-256 해시를 그대로 반환하고,
+    /// 다르거나 캐시에 없으면 해시를 다시 계산해 캐시를 갱신합니다.
+    /// 수정 시간의 정밀도가 낮은 파일 시스템에서는 같은 시각 안의 변경을 놓칠 수 있습니다.
+    pub fn cached_hash(&self, cache: &mut HashMap<PathBuf, (u64, SystemTime, String)>) -> Result<String> {
+        self.cached_hash_with(cache, || std::fs::File::open(&self.path))
+    }
+
+    // 캐시가 맞지 않을 때만 `open`으로 파일을 열어 해시를 계산합니다. 테스트에서 읽기 여부를 확인할 수 있도록 분리했습니다.
+    fn cached_hash_with<R, F>(&self, cache: &mut HashMap<PathBuf, (u64, SystemTime, String)>, open: F) -> Result<String>
+    where
+        R: Read,
+        F: FnOnce() -> Result<R>,
+    {
+        let metadata = self.metadata()?;
+        let (len, modified) = (metadata.len(), metadata.modified()?);
+
+        if let Some((cached_len, cached_modified, hash)) = cache.get(&self.path)
+            && *cached_len == len
+            && *cached_modified == modified
+        {
+            return Ok(hash.clone());
+        }
+
+        let hash = to_hex(&digest_reader(open()?, HashAlgorithm::Sha256, self.buffer_size)?);
+        cache.insert(self.path.clone(), (len, modified, hash.clone()));
+        Ok(hash)
+    }
+
+    /// 텍스트 파일을 최대 `lines_per_chunk`줄씩 나누어 `out_dir`에 `<이름>.001`, `<이름>.002` 순서로 기록합니다.
+    /// 줄은 중간에서 나뉘지 않고 원래의 줄바꿈을 그대로 유지하며, 만든 파일 목록을 순서대로 반환합니다.
+    pub fn split_by_lines<P: AsRef<Path>>(&self, lines_per_chunk: usize, out_dir: P) -> Result<Vec<File>> {
+        if lines_per_chunk == 0 {
+            return Err(Error::new(ErrorKind::InvalidInput, "조각당 줄 수는 1 이상이어야 합니다"));
+        }
+
+        let name = self.file_name_or_err()?.to_string_lossy().into_owned();
+        let out_dir = out_dir.as_ref();
+        std::fs::create_dir_all(out_dir)?;
+
+        let mut reader = BufReader::with_capacity(self.buffer_size, std::fs::File::open(&self.path)?);
+        let mut chunks = Vec::new();
+        let mut line = Vec::new();
+        loop {
+            if reader.read_until(b'\n', &mut line)? == 0 {
+                break;
+            }
+
+            let chunk = File::new(out_dir.join(format!("{}.{:03}", name, chunks.len() + 1)));
+            let mut writer = std::io::BufWriter::new(std::fs::File::create(&chunk.path)?);
+            writer.write_all(&line)?;
+            for _ in 1..lines_per_chunk {
+                line.clear();
+                if reader.read_until(b'\n', &mut line)? == 0 {
+                    break;
+                }
+                writer.write_all(&line)?;
+            }
+            writer.flush()?;
+
+            line.clear();
+            chunks.push(chunk);
+        }
+
+        Ok(chunks)
+    }
+
+    /// 디스크의 캐시 파일에서 `(경로, 알고리즘)`에 대한 해시를 찾아, 크기와 수정 시간이 같으면 그대로 반환합니다.
+    /// 다르거나 없으면 해시를 계산하고 캐시 파일을 원자적으로 다시 씁니다. 캐시는 한 줄에 한 항목을
+    /// `해시\t크기\t수정 시각(ns)\t알고리즘\t경로` 형식으로 저장하며, 해석할 수 없는 줄은 무시합니다.
+    pub fn load_or_compute_hash(&self, cache_path: &Path, algo: HashAlgorithm) -> Result<String> {
+        let metadata = self.metadata()?;
+        let len = metadata.len();
+        let modified = metadata
+            .modified()?
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .map(|since| since.as_nanos())
+            .ok();
+
+        let content = match std::fs::read_to_string(cache_path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let mut entries: BTreeMap<(String, String), (u64, u128, String)> = BTreeMap::new();
+        for line in content.lines() {
+            let fields: Vec<&str> = line.splitn(5, '\t').collect();
+            if let [hash, len, modified, algo, path] = fields[..]
+                && let (Ok(len), Ok(modified)) = (len.parse(), modified.parse())
+            {
+                entries.insert((path.to_string(), algo.to_string()), (len, modified, hash.to_string()));
+            }
+        }
+
+        // 줄 단위 형식에 담을 수 없는 경로나 1970년 이전 시각은 캐시하지 않습니다.
+        let key = self
+            .path
+            .to_str()
+            .filter(|path| !path.contains(['\n', '\r']))
+            .map(|path| (path.to_string(), algo.extension().to_string()));
+        let (Some(key), Some(modified)) = (key, modified) else {
+            return self.hash_with(algo);
+        };
+
+        if let Some((cached_len, cached_modified, hash)) = entries.get(&key)
+            && *cached_len == len
+            && *cached_modified == modified
+        {
+            return Ok(hash.clone());
+        }
+
+        let hash = self.hash_with(algo)?;
+        entries.insert(key, (len, modified, hash.clone()));
+
+        let serialized: String = entries
+            .iter()
+            .map(|((path, algo), (len, modified, hash))| {
+                format!("{}\t{}\t{}\t{}\t{}\n", hash, len, modified, algo, path)
+            })
+            .collect();
+        File::new(cache_path).write_atomic(serialized)?;
+        Ok(hash)
+    }
+
+    /// 파일 앞부분의 매직 바이트로 형식을 판별하고, 알려진 형식이 아니면 텍스트인지 추정합니다.
+    /// 앞의 최대 8 KiB가 NUL 없는 UTF-8이면 `PlainText`(빈 파일 포함), 그렇지 않으면 `Unknown`을 반환합니다.
+    pub fn identify(&self) -> Result<FileFormat> {
+        const SAMPLE_SIZE: usize = 8 * 1024;
+
+        let mut sample = vec![0u8; SAMPLE_SIZE];
+        let read = read_full(&mut std::fs::File::open(&self.path)?, &mut sample)?;
+        sample.truncate(read);
+
+        if let Some(&(_, format)) = MAGIC_BYTES.iter().find(|(magic, _)| sample.starts_with(magic)) {
+            return Ok(format);
+        }
+
+        let is_text = match std::str::from_utf8(&sample) {
+            Ok(_) => true,
+            // 표본의 끝에서 잘린 멀티바이트 문자는 텍스트로 간주합니다.
+            Err(e) => e.error_len().is_none() && read == SAMPLE_SIZE,
+        };
+        Ok(if is_text && !sample.contains(&0) { FileFormat::PlainText } else { FileFormat::Unknown })
+    }
+
+    /// 디렉터리 아래 모든 파일의 수정 시간을 현재 시각으로 바꾸고 바꾼 파일 수를 반환합니다.
+    /// 심볼릭 링크는 건너뛰며, 디렉터리까지 바꾸려면 `touch_tree_with`를 사용합니다.
+    pub fn touch_tree(&self) -> Result<usize> {
+        self.touch_tree_with(false)
+    }
+
+    /// `touch_tree`와 같지만, `include_dirs`가 `true`이면 하위 디렉터리의 수정 시간도 바꾸고 개수에 포함합니다.
+    pub fn touch_tree_with(&self, include_dirs: bool) -> Result<usize> {
+        self.ensure_dir()?;
+
+        let now = SystemTime::now();
+        let mut touched = 0;
+        visit_entries(&self.path, &mut |entry| {
+            let file_type = entry.file_type()?;
+            if file_type.is_file() || (include_dirs && file_type.is_dir()) {
+                open_for_times(&entry.path())?.set_modified(now)?;
+                touched += 1;
+            }
+            Ok(())
+        })?;
+
+        Ok(touched)
+    }
+
+    /// 두 파일의 크기, 수정 시간, 권한, 종류 중 어떤 것이 다른지 각각의 값과 함께 반환합니다.
+    /// 내용은 비교하지 않으므로, 내용을 다시 확인할지 결정하는 데 사용합니다.
+    pub fn metadata_diff(&self, other: &File) -> Result<MetadataDiff> {
+        let (a, b) = (self.metadata()?, other.metadata()?);
+        let sizes = (a.len(), b.len());
+        let modified = (a.modified()?, b.modified()?);
+        let permissions = (a.permissions(), b.permissions());
+        let file_kinds = (FileKind::from(a.file_type()), FileKind::from(b.file_type()));
+
+        Ok(MetadataDiff {
+            size_differs: sizes.0 != sizes.1,
+            modified_differs: modified.0 != modified.1,
+            permissions_differ: permissions.0 != permissions.1,
+            file_type_differs: file_kinds.0 != file_kinds.1,
+            sizes,
+            modified,
+            permissions,
+            file_kinds,
+        })
+    }
+
+    /// 디렉터리 아래에서 수정 시간이 `지금 - max_age`보다 오래된 일반 파일을 삭제하고 삭제한 수를 반환합니다.
+    /// 디렉터리와 심볼릭 링크는 건드리지 않으며, 비게 된 디렉터리까지 정리하려면 `rm_older_than_with`를 사용합니다.
+    pub fn rm_older_than(&self, max_age: Duration) -> Result<usize> {
+        self.rm_older_than_with(max_age, false)
+    }
+
+    /// `rm_older_than`과 같지만, `prune_empty_dirs`가 `true`이면 삭제 후 파일이 없는 하위 디렉터리도 삭제합니다.
+    /// 루트 디렉터리 자신은 삭제하지 않으며, 반환값에는 삭제한 파일 수만 포함됩니다.
+    pub fn rm_older_than_with(&self, max_age: Duration, prune_empty_dirs: bool) -> Result<usize> {
+        self.ensure_dir()?;
+
+        let cutoff = SystemTime::now().checked_sub(max_age).unwrap_or(SystemTime::UNIX_EPOCH);
+        let mut expired = Vec::new();
+        visit_entries(&self.path, &mut |entry| {
+            if entry.file_type()?.is_file() && entry.metadata()?.modified()? < cutoff {
+                expired.push(entry.path());
+            }
+            Ok(())
+        })?;
+
+        for path in &expired {
+            std::fs::remove_file(path)?;
+        }
+
+        if prune_empty_dirs {
+            self.prune_empty_dirs()?;
+        }
+
+        Ok(expired.len())
+    }
+
+    /// 파일을 가리키는 하드 링크의 개수를 반환합니다. 새로 만든 파일은 1입니다.
+    /// Unix에서는 `st_nlink`를, Windows에서는 `GetFileInformationByHandle`의 링크 수를 사용합니다.
+    pub fn hard_link_count(&self) -> Result<u64> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            Ok(self.metadata()?.nlink())
+        }
+
+        #[cfg(windows)]
+        {
+            Ok(u64::from(file_information(&self.path)?.nNumberOfLinks))
+        }
+
+        #[cfg(not(any(unix, windows)))]
+        {
+            self.metadata()?;
+            Err(Error::new(ErrorKind::Unsupported, "이 플랫폼에서는 하드 링크 수를 조회할 수 없습니다"))
+        }
+    }
+
+    /// 줄 목록을 지정한 줄바꿈으로 이어 원자적으로 기록합니다. `Lf`와 `CrLf`는 마지막 줄 뒤에도 줄바꿈을 붙이고,
+    /// `None`은 `\n`으로 잇되 마지막 줄바꿈을 생략합니다. 쓰기 방식이 정해지지 않은 `Mixed`는 `InvalidInput` 오류입니다.
+    pub fn write_lines<I, S>(&self, lines: I, ending: LineEnding) -> Result<()>
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        let (separator, trailing) = match ending {
+            LineEnding::Lf => ("\n", true),
+            LineEnding::CrLf => ("\r\n", true),
+            LineEnding::None => ("\n", false),
+            LineEnding::Mixed => {
+                return Err(Error::new(ErrorKind::InvalidInput, "Mixed로는 줄바꿈 방식을 정할 수 없습니다"));
+            }
+        };
+
+        let mut content = String::new();
+        let mut count = 0;
+        for line in lines {
+            if count > 0 {
+                content.push_str(separator);
+            }
+            content.push_str(line.as_ref());
+            count += 1;
+        }
+        if trailing && count > 0 {
+            content.push_str(separator);
+        }
+
+        self.write_atomic(content)
+    }
+
+    /// 두 텍스트 파일을 LCS(최장 공통 부분 수열)로 비교하여 줄 단위 변경 목록을 반환합니다.
+    /// 자신을 이전 판, `other`를 새 판으로 보며, 바뀐 줄은 `Removed` 다음에 `Added`로 나타납니다.
+    /// 줄 수의 곱에 비례하는 메모리를 사용하므로 작은 설정 파일이나 텍스트 파일에만 적합합니다.
+    pub fn diff_lines(&self, other: &File) -> Result<Vec<LineChange>> {
+        let (old, new) = (self.read_lines()?, other.read_lines()?);
+        let (n, m) = (old.len(), new.len());
+
+        // lcs[i][j]는 old[i..]와 new[j..]의 최장 공통 부분 수열 길이입니다.
+        let mut lcs = vec![vec![0usize; m + 1]; n + 1];
+        for i in (0..n).rev() {
+            for j in (0..m).rev() {
+                lcs[i][j] = if old[i] == new[j] { lcs[i + 1][j + 1] + 1 } else { lcs[i + 1][j].max(lcs[i][j + 1]) };
+            }
+        }
+
+        let mut changes = Vec::with_capacity(n.max(m));
+        let (mut i, mut j) = (0, 0);
+        while i < n && j < m {
+            if old[i] == new[j] {
+                changes.push(LineChange::Unchanged(old[i].clone()));
+                i += 1;
+                j += 1;
+            } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+                changes.push(LineChange::Removed(old[i].clone()));
+                i += 1;
+            } else {
+                changes.push(LineChange::Added(new[j].clone()));
+                j += 1;
+            }
+        }
+        changes.extend(old[i..].iter().cloned().map(LineChange::Removed));
+        changes.extend(new[j..].iter().cloned().map(LineChange::Added));
+
+        Ok(changes)
+    }
+
+    /// 두 경로가 같은 파일 시스템에 있는지 확인합니다. `false`이면 `rename`이 `CrossesDevices`로 실패합니다.
+    /// Unix에서는 `st_dev`를, Windows에서는 볼륨 일련번호를 비교합니다.
+    pub fn is_same_filesystem(&self, other: &File) -> Result<bool> {
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::MetadataExt;
+            Ok(self.metadata()?.dev() == other.metadata()?.dev())
+        }
+
+        #[cfg(windows)]
+        {
+            Ok(file_information(&self.path)?.dwVolumeSerialNumber == file_information(&other.path)?.dwVolumeSerialNumber)
+        }
+
+        #[cfg(not(any(unix, windows)))]
+        {
+            let _ = other;
+            Err(Error::new(ErrorKind::Unsupported, "이 플랫폼에서는 파일 시스템을 비교할 수 없습니다"))
+        }
+    }
+
+    /// 중단된 복사를 이어서 진행합니다. 대상 파일이 원본의 앞부분과 같으면(SHA-256으로 확인)
+    /// 남은 바이트만 덧붙이고, 대상이 없거나 앞부분이 다르면 처음부터 다시 복사합니다.
+    pub fn resume_copy_to<P: AsRef<Path>>(&self, dest: P) -> Result<File> {
+        let dest = dest.as_ref();
+        let source_len = self.metadata()?.len();
+        let existing_len = match std::fs::metadata(dest) {
+            Ok(metadata) if metadata.is_file() => metadata.len(),
+            Ok(_) => return Err(Error::new(ErrorKind::InvalidInput, "대상 경로가 파일이 아닙니다")),
+            Err(e) if e.kind() == ErrorKind::NotFound => return self.copy_to(dest),
+            Err(e) => return Err(e),
+        };
+
+        if existing_len > source_len {
+            return self.copy_to(dest);
+        }
+
+        let mut source = std::fs::File::open(&self.path)?;
+        let prefix = digest_reader((&mut source).take(existing_len), HashAlgorithm::Sha256, self.buffer_size)?;
+        let existing = digest_reader(std::fs::File::open(dest)?, HashAlgorithm::Sha256, self.buffer_size)?;
+        if prefix != existing {
+            return self.copy_to(dest);
+        }
+
+        // 해시 계산으로 원본의 읽기 위치가 이미 기존 길이만큼 이동해 있습니다.
+        let writer = OpenOptions::new().append(true).open(dest)?;
+        copy_stream(source, writer, self.buffer_size)?;
+        Ok(File::new(dest))
+    }
+
+    /// 배타적 잠금을 건 상태에서 `f`를 실행하고 결과를 반환합니다.
+    /// 잠금은 가드가 보유하므로 `f`가 패닉하더라도 스택이 풀리면서 해제됩니다.
+    pub fn with_lock<T, F: FnOnce() -> T>(&self, f: F) -> Result<T> {
+        let _lock = self.lock()?;
+        Ok(f())
+    }
+
+    /// 디렉터리 바로 아래에 있는 일반 파일의 개수를 반환합니다. 심볼릭 링크는 세지 않습니다.
+    pub fn count_files(&self) -> Result<usize> {
+        self.count_entries_of(FileKind::File, false)
+    }
+
+    /// 디렉터리 바로 아래에 있는 하위 디렉터리의 개수를 반환합니다.
+    pub fn count_dirs(&self) -> Result<usize> {
+        self.count_entries_of(FileKind::Dir, false)
+    }
+
+    /// 하위 디렉터리를 재귀적으로 순회하며 일반 파일의 개수를 반환합니다.
+    /// 디렉터리를 가리키는 심볼릭 링크는 따라가지 않습니다.
+    pub fn count_files_recursive(&self) -> Result<usize> {
+        self.count_entries_of(FileKind::File, true)
+    }
+
+    /// 하위 디렉터리를 재귀적으로 순회하며 디렉터리의 개수를 반환합니다(자기 자신은 제외).
+    pub fn count_dirs_recursive(&self) -> Result<usize> {
+        self.count_entries_of(FileKind::Dir, true)
+    }
+
+    // 항목별 `stat` 없이 디렉터리 항목의 종류만으로 `kind`에 해당하는 항목을 셉니다.
+    fn count_entries_of(&self, kind: FileKind, recursive: bool) -> Result<usize> {
+        let mut count = 0;
+
+        for (entry, entry_kind) in self.entries_with_type()? {
+            if entry_kind == kind {
+                count += 1;
+            }
+            if recursive && entry_kind == FileKind::Dir {
+                count += entry.count_entries_of(kind, true)?;
+            }
+        }
+
+        Ok(count)
+    }
+
+    /// 파일을 스트리밍으로 해싱하여 원시 다이제스트 바이트가 `expected`와 같은지 확인합니다.
+    /// 16진수 변환 없이 비교하며, `expected`의 길이가 알고리즘의 출력 길이와 다르면 오류 대신 `false`를 반환합니다.
+    pub fn digest_matches(&self, algo: HashAlgorithm, expected: &[u8]) -> Result<bool> {
+        Ok(self.digest(algo)? == expected)
+    }
+
+    /// 트리의 모든 파일을 하위 디렉터리 없이 `dest` 한 곳으로 복사하고 복사된 파일 목록을 반환합니다.
+    /// 이름이 겹치면 `foo (1).txt`처럼 번호를 붙이며, 디렉터리가 없으면 생성합니다.
+    pub fn flatten_into<P: AsRef<Path>>(&self, dest: P) -> Result<Vec<File>> {
+        self.ensure_dir()?;
+        let dest = dest.as_ref();
+
+        // 대상이 트리 안에 있어도 복사한 파일을 다시 순회하지 않도록 목록을 먼저 만듭니다.
+        let mut sources = Vec::new();
+        visit_entries(&self.path, &mut |entry| {
+            if entry.file_type()?.is_file() {
+                sources.push(File::new(entry.path()));
+            }
+            Ok(())
+        })?;
+
+        std::fs::create_dir_all(dest)?;
+        sources
+            .iter()
+            .map(|source| source.copy_to_with_policy(dest.join(source.file_name_or_err()?), OverwritePolicy::Rename))
+            .collect()
+    }
+
+    /// 경로의 이름 구성 요소 개수를 반환합니다. 예: `a/b/c`와 `/a/b/c`는 모두 3입니다.
+    /// 루트, 드라이브 접두사, `.`은 세지 않으며 파일 시스템에 접근하지 않습니다.
+    pub fn depth(&self) -> usize {
+        self.path
+            .components()
+            .filter(|c| matches!(c, std::path::Component::Normal(_) | std::path::Component::ParentDir))
+            .count()
+    }
+
+    /// `base` 아래에서의 상대 깊이를 반환합니다. 예: `base/x/y`는 2, `base` 자신은 0입니다.
+    /// 경로를 구성 요소 단위로만 비교하며, `base` 아래가 아니면 `None`을 반환합니다.
+    pub fn depth_from(&self, base: &File) -> Option<usize> {
+        let relative = self.path.strip_prefix(&base.path).ok()?;
+        Some(File::new(relative).depth())
+    }
+
+    /// 파일에 저장된 정수를 1 증가시켜 원자적으로 기록하고 새 값을 반환합니다.
+    /// 파일이 없거나 비어 있으면 0으로 간주합니다. 원자적 교체는 파일을 바꿔치기하므로,
+    /// 잠금은 파일 자체가 아닌 옆의 `<이름>.lock` 파일에 걸어 프로세스 간에도 갱신이 유실되지 않게 합니다.
+    pub fn increment_counter(&self) -> Result<u64> {
+        let mut lock_name = self.file_name_or_err()?.to_os_string();
+        lock_name.push(".lock");
+
+        File::new(self.path.with_file_name(lock_name)).with_lock(|| {
+            let current = match std::fs::read_to_string(&self.path) {
+                Ok(content) if content.trim().is_empty() => 0,
+                Ok(content) => content
+                    .trim()
+                    .parse::<u64>()
+                    .map_err(|e| Error::new(ErrorKind::InvalidData, e))?,
+                Err(e) if e.kind() == ErrorKind::NotFound => 0,
+                Err(e) => return Err(e),
+            };
+
+            let next = current
+                .checked_add(1)
+                .ok_or_else(|| Error::new(ErrorKind::InvalidData, "카운터 값이 범위를 벗어났습니다"))?;
+            self.write_atomic(next.to_string())?;
+            Ok(next)
+        })?
+    }
+
+    /// 두 디렉터리 트리를 비교하여 한쪽에만 있는 파일과 내용이 다른 파일을 함께 보고합니다.
+    /// 양쪽에 모두 있는 파일은 크기를 먼저 비교하고, 크기가 같을 때만 SHA-256으로 내용을 비교합니다.
+    pub fn tree_compare(&self, other: &File) -> Result<TreeReport> {
+        self.ensure_dir()?;
+        other.ensure_dir()?;
+
+        let ours = file_sizes(&self.path)?;
+        let theirs = file_sizes(&other.path)?;
+        let mut report = TreeReport::default();
+
+        for (relative, size) in &ours {
+            match theirs.get(relative) {
+                None => report.only_in_self.push(relative.clone()),
+                Some(other_size) if other_size != size => report.content_differs.push(relative.clone()),
+                Some(_) => {
+                    let a = File::new(self.path.join(relative)).with_buffer_size(self.buffer_size);
+                    let b = File::new(other.path.join(relative)).with_buffer_size(self.buffer_size);
+                    if a.digest(HashAlgorithm::Sha256)? != b.digest(HashAlgorithm::Sha256)? {
+                        report.content_differs.push(relative.clone());
+                    }
+                }
+            }
+        }
+        report.only_in_other = theirs.keys().filter(|path| !ours.contains_key(*path)).cloned().collect();
+
+        Ok(report)
+    }
+
+    /// 파일의 모든 줄바꿈(`\n`, `\r\n`)을 `to` 방식으로 바꾸고, 내용이 바뀌었는지 반환합니다.
+    /// 이미 `to` 방식이면 파일을 다시 쓰지 않으므로 수정 시간도 유지됩니다. 단독 `\r`은 건드리지 않습니다.
+    /// `to`가 `Mixed`나 `None`이면 `InvalidInput` 오류를 반환합니다.
+    pub fn normalize_newlines(&self, to: LineEnding) -> Result<bool> {
+        let separator: &[u8] = match to {
+            LineEnding::Lf => b"\n",
+            LineEnding::CrLf => b"\r\n",
+            LineEnding::Mixed | LineEnding::None => {
+                return Err(Error::new(ErrorKind::InvalidInput, "Lf 또는 CrLf로만 변환할 수 있습니다"));
+            }
+        };
+
+        let content = std::fs::read(&self.path)?;
+        let mut normalized = Vec::with_capacity(content.len());
+        let mut index = 0;
+        while index < content.len() {
+            match content[index] {
+                b'\r' if content.get(index + 1) == Some(&b'\n') => {
+                    normalized.extend_from_slice(separator);
+                    index += 2;
+                }
+                b'\n' => {
+                    normalized.extend_from_slice(separator);
+                    index += 1;
+                }
+                byte => {
+                    normalized.push(byte);
+                    index += 1;
+                }
+            }
+        }
+
+        if normalized == content {
+            return Ok(false);
+        }
+        self.write_atomic(normalized)?;
+        Ok(true)
+    }
+
+    /// 두 파일의 처음 `len` 바이트만 스트리밍으로 비교하여 같은지 확인합니다.
+    /// 그 뒤의 내용이나 전체 길이는 보지 않습니다. 한쪽만 `len`보다 짧으면 앞부분이 같아도 `false`입니다.
+    pub fn is_deep_match_prefix(&self, other: &File, len: u64) -> Result<bool> {
+        let mut a = std::fs::File::open(&self.path)?.take(len);
+        let mut b = std::fs::File::open(&other.path)?.take(len);
+        let mut buffer_a = vec![0u8; self.buffer_size];
+        let mut buffer_b = vec![0u8; self.buffer_size];
+
+        loop {
+            let read_a = read_full(&mut a, &mut buffer_a)?;
+            let read_b = read_full(&mut b, &mut buffer_b)?;
+
+            if buffer_a[..read_a] != buffer_b[..read_b] {
+                return Ok(false);
+            }
+            if read_a == 0 {
+                return Ok(true);
+            }
+        }
+    }
+
+    /// 지정한 알고리즘으로 해시 값을 계산하면서, 버퍼 하나를 처리할 때마다 `progress(해싱한 바이트, 전체 바이트)`를 호출합니다.
+    /// 전체 바이트는 시작 시점의 파일 크기이므로, 해싱 중에 파일이 커지면 진행량이 이를 넘을 수 있습니다.
+    pub fn hash_with_progress<F: FnMut(u64, u64)>(&self, algo: HashAlgorithm, mut progress: F) -> Result<String> {
+        let mut handle = std::fs::File::open(&self.path)?;
+        let total = handle.metadata()?.len();
+        let mut hasher = Hasher::new(algo);
+        let mut buffer = vec![0u8; self.buffer_size];
+        let mut hashed = 0u64;
+
+        loop {
+            let read = match handle.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            hasher.update(&buffer[..read]);
+            hashed += read as u64;
+            progress(hashed, total);
+        }
+
+        Ok(hasher.finalize())
+    }
+
+    /// 파일 또는 디렉터리 트리를 삭제하고, 삭제 전에 계산한 파일 크기의 합을 반환합니다.
+    /// 경로가 없으면 `Ok(0)`을 반환합니다. 심볼릭 링크는 따라가지 않고 링크만 삭제하며 0바이트로 셉니다.
+    pub fn rm_reporting(&self) -> Result<u64> {
+        let metadata = match std::fs::symlink_metadata(&self.path) {
+            Ok(metadata) => metadata,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(0),
+            Err(e) => return Err(e),
+        };
+
+        if metadata.is_dir() {
+            let freed = self.tree_stats()?.total_bytes;
+            std::fs::remove_dir_all(&self.path)?;
+            Ok(freed)
+        } else {
+            std::fs::remove_file(&self.path)?;
+            Ok(if metadata.is_file() { metadata.len() } else { 0 })
+        }
+    }
+
+    /// `git hash-object`와 같은 Git 블롭 ID를 반환합니다.
+    /// `blob <길이>\0` 헤더 뒤에 파일 내용을 이어 붙인 바이트의 SHA-1을 16진수로 계산합니다.
+    pub fn git_blob_hash(&self) -> Result<String> {
+        let mut handle = std::fs::File::open(&self.path)?;
+        let len = handle.metadata()?.len();
+
+        let mut hasher = sha1::Sha1::new();
+        hasher.update(format!("blob {}\0", len).as_bytes());
+        let mut buffer = vec![0u8; self.buffer_size];
+        let mut hashed = 0u64;
+        loop {
+            let read = match handle.read(&mut buffer) {
+                Ok(0) => break,
+                Ok(read) => read,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(e),
+            };
+            hasher.update(&buffer[..read]);
+            hashed += read as u64;
+        }
+
+        // 헤더에 기록한 길이와 실제로 읽은 길이가 다르면 Git과 다른 값이 되므로 오류로 처리합니다.
+        if hashed != len {
+            return Err(Error::new(ErrorKind::InvalidData, "해싱하는 동안 파일 크기가 바뀌었습니다"));
+        }
+        Ok(to_hex(&hasher.finalize()))
+    }
+
+    /// 하위 트리에 파일이 하나도 없는 디렉터리를 아래에서부터 삭제하고 삭제한 개수를 반환합니다.
+    /// 비어 있는 디렉터리만 포함한 디렉터리도 함께 삭제하지만, 루트 자신은 삭제하지 않습니다.
+    pub fn prune_empty_dirs(&self) -> Result<usize> {
+        let empty_dirs = self.find_empty_dirs()?;
+
+        // 경로순으로 정렬되어 있으므로 역순으로 지우면 하위 디렉터리가 먼저 지워집니다.
+        for dir in empty_dirs.iter().rev() {
+            std::fs::remove_dir(&dir.path)?;
+        }
+
+        Ok(empty_dirs.len())
+    }
+
+    /// `offset` 위치부터 최대 `len` 바이트를 읽어 반환합니다.
+    /// 파일 끝에 먼저 도달하면 남은 만큼만 반환하며, `offset`이 파일 끝을 넘으면 빈 벡터를 반환합니다.
+    /// 버퍼는 파일에 실제로 남은 크기까지만 할당하므로, 신뢰할 수 없는 `len`을 넘겨도 됩니다.
+    pub fn read_range(&self, offset: u64, len: usize) -> Result<Vec<u8>> {
+        let mut handle = std::fs::File::open(&self.path)?;
+        let available = handle.metadata()?.len().saturating_sub(offset);
+        handle.seek(SeekFrom::Start(offset))?;
+
+        let mut buffer = Vec::with_capacity(usize::try_from(available).unwrap_or(usize::MAX).min(len));
+        handle.take(len as u64).read_to_end(&mut buffer)?;
+        Ok(buffer)
+    }
+
+    /// `read_range`와 같지만, `offset` 위치에 `len` 바이트가 모두 있지 않으면 `UnexpectedEof` 오류를 반환합니다.
+    /// 바이너리 형식을 파싱할 때 잘린 파일을 조용히 넘기지 않기 위한 것으로, 범위를 먼저 확인한 뒤에 버퍼를 할당합니다.
+    pub fn read_range_exact(&self, offset: u64, len: usize) -> Result<Vec<u8>> {
+        let eof = |available: u64| {
+            Error::new(
+                ErrorKind::UnexpectedEof,
+                format!("{}번 위치에서 {} bytes를 읽어야 하지만 {} bytes만 남아 있습니다", offset, len, available),
+            )
+        };
+
+        let available = self.len()?.saturating_sub(offset);
+        if (len as u64) > available {
+            return Err(eof(available));
+        }
+
+        // 확인 후 파일이 줄어든 경우에도 잘린 결과를 돌려주지 않습니다.
+        let buffer = self.read_range(offset, len)?;
+        if buffer.len() < len {
+            return Err(eof(buffer.len() as u64));
+        }
+        Ok(buffer)
+    }
+
+    /// 심볼릭 링크를 따라가지 않고 링크 자체의 크기를 반환합니다. 링크가 아니면 `len`과 같습니다.
+    /// Unix에서는 대상 경로 문자열의 바이트 길이이며, Windows에서는 보통 0입니다.
+    pub fn link_size(&self) -> Result<u64> {
+        Ok(self.metadata_nofollow()?.len())
+    }
+
+    /// 디렉터리의 항목을 자연 정렬 순서로 반환합니다. `file2`가 `file10`보다 앞에 옵니다.
+    /// 이름은 숫자 부분을 수의 크기로, 나머지는 문자 단위로 비교합니다.
+    pub fn read_dir_natural(&self) -> Result<Vec<File>> {
+        self.ensure_dir()?;
+
+        let mut entries = read_dir_sorted(&self.path)?;
+        entries.sort_by(|a, b| natural_cmp(&a.file_name().to_string_lossy(), &b.file_name().to_string_lossy()));
+        Ok(entries.iter().map(|entry| File::new(entry.path())).collect())
+    }
+
+    /// 맨 앞의 UTF-8 BOM을 제외한 내용의 해시 값을 반환합니다.
+    /// BOM이 있는 파일과 없는 파일의 텍스트가 같으면 같은 해시가 됩니다.
+    pub fn hash_text(&self, algo: HashAlgorithm) -> Result<String> {
+        self.hash_text_with(algo, false)
+    }
+
+    /// `hash_text`와 같지만, `normalize_newlines`가 `true`이면 `\r\n`을 `\n`으로 바꿔 해싱합니다.
+    /// 단독 `\r`은 그대로 둡니다.
+    pub fn hash_text_with(&self, algo: HashAlgorithm, normalize_newlines: bool) -> Result<String> {
+        let mut handle = std::fs::File::open(&self.path)?;
+        let mut hasher = Hasher::new(algo);
+        let mut buffer = vec![0u8; self.buffer_size.max(UTF8_BOM.len())];
+        let mut pending_cr = false;
+        let mut first = true;
+
+        loop {
+            let read = read_full(&mut handle, &mut buffer)?;
+            if read == 0 {
+                break;
+            }
+
+            let mut chunk = &buffer[..read];
+            if first {
+                chunk = chunk.strip_prefix(UTF8_BOM).unwrap_or(chunk);
+                first = false;
+            }
+
+            if !normalize_newlines {
+                hasher.update(chunk);
+                continue;
+            }
+
+            // 버퍼 경계에서 `\r`과 `\n`이 나뉘어도 하나의 줄바꿈으로 처리합니다.
+            let mut normalized = Vec::with_capacity(chunk.len() + 1);
+            for &byte in chunk {
+                if pending_cr {
+                    pending_cr = false;
+                    if byte == b'\n' {
+                        normalized.push(b'\n');
+                        continue;
+                    }
+                    normalized.push(b'\r');
+                }
+                if byte == b'\r' {
+                    pending_cr = true;
+                } else {
+                    normalized.push(byte);
+                }
+            }
+            hasher.update(&normalized);
+        }
+
+        if pending_cr {
+            hasher.update(b"\r");
+        }
+        Ok(hasher.finalize())
+    }
+
+    /// 파일 내용을 임의의 Writer(소켓, 압축기 등)로 스트리밍하고 기록한 바이트 수를 반환합니다.
+    /// 버퍼 하나 외에는 추가로 할당하지 않으며, 끝나면 Writer를 `flush`합니다.
+    pub fn copy_to_writer<W: Write>(&self, writer: W) -> Result<u64> {
+        copy_stream(std::fs::File::open(&self.path)?, writer, self.buffer_size)
+    }
+
+    /// 파일이 없으면 만들고, 길이를 정확히 `size` 바이트로 맞춥니다.
+    /// 늘어나는 부분은 0으로 채워지고, 줄어들면 뒷부분이 잘립니다.
+    pub fn set_exact_size(&self, size: u64) -> Result<()> {
+        let handle = OpenOptions::new().write(true).create(true).truncate(false).open(&self.path)?;
+        handle.set_len(size)
+    }
+
+    /// 두 텍스트 파일을 BOM, 줄바꿈 방식(`\n`/`\r\n`), 각 줄 끝의 공백을 무시하고 비교합니다.
+    /// 마지막 줄바꿈의 유무는 구분하며, UTF-8이 아닌 파일은 `InvalidData` 오류를 반환합니다.
+    pub fn is_text_match_normalized(&self, other: &File) -> Result<bool> {
+        self.is_text_match_normalized_with(other, false)
+    }
+
+    /// `is_text_match_normalized`와 같지만, `ignore_final_newline`이 `true`이면 파일 끝 줄바꿈의 유무도 무시합니다.
+    pub fn is_text_match_normalized_with(&self, other: &File, ignore_final_newline: bool) -> Result<bool> {
+        let ours = std::fs::read_to_string(&self.path)?;
+        let theirs = std::fs::read_to_string(&other.path)?;
+        Ok(normalized_text_lines(&ours, ignore_final_newline) == normalized_text_lines(&theirs, ignore_final_newline))
+    }
+
+    /// 경로가 비어 있으면 그대로, 이미 존재하면 확장자 앞에 ` (1)`, ` (2)` 등을 붙여 처음으로 비어 있는 경로를 반환합니다.
+    /// 예: `foo.txt`와 `foo (1).txt`가 있으면 `foo (2).txt`입니다. 확인과 생성 사이의 경쟁은 막지 않으므로,
+    /// 여러 호출자가 동시에 쓸 수 있다면 `copy_to_with_policy`의 `Rename`처럼 `create_new`로 만들어야 합니다.
+    pub fn next_available(&self) -> File {
+        if !self.path.exists() {
+            return self.clone();
+        }
+
+        let mut n = 1;
+        loop {
+            let candidate = numbered_path(&self.path, n);
+            if !candidate.exists() {
+                return File::new(candidate);
+            }
+            n += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::io::Cursor;
+    use std::io::Write;
+
+    // 테스트용 임시 디렉터리 경로를 생성하고 정리합니다.
+    pub(super) fn setup_test_env(test_name: &str) -> PathBuf {
+        let temp_dir = std::env::temp_dir().join("files_test").join(test_name);
+        if temp_dir.exists() {
+            fs::remove_dir_all(&temp_dir).unwrap();
+        }
+        fs::create_dir_all(&temp_dir).unwrap();
+        temp_dir
+    }
+
+    #[test]
+    fn test_file_creation_and_checks() {
+        let test_dir = setup_test_env("test_file_creation_and_checks");
+        let file_path = test_dir.join("test_file.txt");
+        let dir_path = test_dir.join("test_dir");
+        fs::File::create(&file_path).unwrap();
+        fs::create_dir(&dir_path).unwrap();
+
+        let file = File::new(&file_path);
+        let dir = File::new(&dir_path);
+        let non_existent = File::new("non_existent_file.txt");
+
+        // exists, is_file, is_dir 테스트
+        assert!(file.exists());
+        assert!(file.is_file());
+        assert!(!file.is_directory());
+
+        assert!(dir.exists());
+        assert!(dir.is_directory());
+        assert!(!dir.is_file());
+
+        assert!(!non_existent.exists());
+    }
+
+    #[test]
+    fn test_rm_file() {
+        let test_dir = setup_test_env("test_rm_file");
+        let file_path = test_dir.join("file_to_delete.txt");
+        fs::File::create(&file_path).unwrap();
+
+        let file = File::new(&file_path);
+        assert!(file.exists());
+
+        // rm 메서드로 파일 삭제
+        file.rm().unwrap();
+        assert!(!file.exists());
+    }
+
+    #[test]
+    fn test_rm_dir() {
+        let test_dir = setup_test_env("test_rm_dir");
+        let dir_path = test_dir.join("dir_to_delete");
+        fs::create_dir_all(&dir_path).unwrap();
+        // 디렉터리 안에 파일 생성
+        fs::File::create(dir_path.join("inner_file.txt")).unwrap();
+
+        let dir = File::new(&dir_path);
+        assert!(dir.exists());
+
+        // rm 메서드로 디렉터리 재귀적으로 삭제
+        dir.rm().unwrap();
+        assert!(!dir.exists());
+    }
+
+    #[test]
+    fn test_rm_non_existent() {
+        let non_existent_file = File::new("path/that/does/not/exist.tmp");
+        // 존재하지 않는 파일을 삭제 시도 시 에러가 발생하지 않고 Ok(())를 반환해야 합니다.
+        // is_file()과 is_dir()가 모두 false이므로 rm()은 아무 작업도 하지 않습니다.
+        assert!(non_existent_file.rm().is_ok());
+    }
+
+    #[test]
+    fn test_file_len() {
+        let test_dir = setup_test_env("test_file_len");
+        let file_path = test_dir.join("file_with_content.txt");
+        let content = b"Hello, World!";
+        fs::write(&file_path, content).unwrap();
+
+        let file = File::new(&file_path);
+        assert_eq!(file.len().unwrap(), content.len() as u64);
+    }
+
+    #[test]
+    fn test_file_is_empty() {
+        let test_dir = setup_test_env("test_file_is_empty");
+        let empty_path = test_dir.join("empty.txt");
+        let full_path = test_dir.join("full.txt");
+        fs::write(&empty_path, b"").unwrap();
+        fs::write(&full_path, b"data").unwrap();
+
+        assert!(File::new(&empty_path).is_empty().unwrap());
+        assert!(!File::new(&full_path).is_empty().unwrap());
+        // len()과 마찬가지로 없는 경로는 오류를 반환해야 합니다.
+        assert!(File::new(test_dir.join("missing.txt")).is_empty().is_err());
+    }
+
+    #[test]
+    fn test_is_match() {
+        let test_dir = setup_test_env("test_is_match");
+        let file1_path = test_dir.join("file1.txt");
+        let file2_path = test_dir.join("file2.txt");
+        let file3_path = test_dir.join("file3.txt");
+
+        fs::write(&file1_path, b"Hello, World!").unwrap();
+        fs::write(&file2_path, b"Hello, World!").unwrap();
+        fs::write(&file3_path, b"Different content").unwrap();
+
+        let file1 = File::new(&file1_path);
+        let file2 = File::new(&file2_path);
+        let file3 = File::new(&file3_path);
+
+        assert!(file1.is_match(&file2));
+        assert!(!file1.is_match(&file3));
+    }
+
+    #[test]
+    fn test_is_deep_match() {
+        let test_dir = setup_test_env("test_is_deep_match");
+        let file1_path = test_dir.join("file1.txt");
+        let file2_path = test_dir.join("file2.txt");
+        let file3_path = test_dir.join("file3.txt");
+
+        fs::write(&file1_path, b"Hello, World!").unwrap();
+        fs::write(&file2_path, b"Hello, World!").unwrap();
+        fs::write(&file3_path, b"Different content").unwrap();
+
+        let file1 = File::new(&file1_path);
+        let file2 = File::new(&file2_path);
+        let file3 = File::new(&file3_path);
+
+        assert!(file1.is_deep_match(&file2));
+        assert!(!file1.is_deep_match(&file3));
+    }
+
+    #[test]
+    fn test_hash_with() {
+        let test_dir = setup_test_env("test_hash_with");
+        let file_path = test_dir.join("file.txt");
+        fs::write(&file_path, b"Hello, World!").unwrap();
+
+        let file = File::new(&file_path);
+        assert_eq!(file.hash_with(HashAlgorithm::Sha256).unwrap(), file.hash());
+        assert_eq!(
+            file.hash_with(HashAlgorithm::Sha224).unwrap(),
+            "72a23dfa411ba6fde01dbfabf3b00a709c93ebf273dc29e2d8b261ff"
+        );
+
+        // 디렉터리는 해싱할 수 없으므로 오류가 발생해야 합니다.
+        assert!(File::new(&test_dir).hash_with(HashAlgorithm::Sha256).is_err());
+    }
+
+    #[test]
+    fn test_hasher_incremental() {
+        let test_dir = setup_test_env("test_hasher_incremental");
+        let file_path = test_dir.join("file.txt");
+        fs::write(&file_path, b"Hello, World!").unwrap();
+
+        // 세 번에 나누어 update한 결과가 파일 해시와 같아야 합니다.
+        let mut hasher = Hasher::new(HashAlgorithm::Sha256);
+        hasher.update(b"Hello");
+        hasher.update(b", ");
+        hasher.update(b"World!");
+
+        assert_eq!(hasher.finalize(), File::new(&file_path).hash());
+    }
+
+    #[test]
+    fn test_stat_once() {
+        let test_dir = setup_test_env("test_stat_once");
+        let file_path = test_dir.join("file.txt");
+        fs::write(&file_path, b"Hello, World!").unwrap();
+
+        let file = File::new(&file_path);
+        let stat = file.stat_once().unwrap();
+        assert_eq!(stat.len, file.len().unwrap());
+        assert_eq!(stat.is_file, file.is_file());
+        assert_eq!(stat.is_dir, file.is_directory());
+        assert_eq!(stat.modified, file.metadata().unwrap().modified().ok());
+
+        let dir_stat = File::new(&test_dir).stat_once().unwrap();
+        assert!(dir_stat.is_dir);
+        assert!(!dir_stat.is_file);
+
+        assert!(File::new(test_dir.join("missing.txt")).stat_once().is_err());
+    }
+
+    #[test]
+    fn test_hash_reader() {
+        let test_dir = setup_test_env("test_hash_reader");
+        let file_path = test_dir.join("file.txt");
+        fs::write(&file_path, b"Hello, World!").unwrap();
+
+        let file = File::new(&file_path);
+        let reader = Cursor::new(b"Hello, World!".to_vec());
+        assert_eq!(hash_reader(reader, HashAlgorithm::Sha256).unwrap(), file.hash());
+
+        assert!(file.is_match_reader(Cursor::new(b"Hello, World!".to_vec())).unwrap());
+        assert!(!file.is_match_reader(Cursor::new(b"Different content".to_vec())).unwrap());
+
+        // 첫 읽기가 중단(EINTR)되어도 다시 시도하여 같은 해시를 계산해야 합니다.
+        struct InterruptOnce<R> {
+            inner: R,
+            interrupted: bool,
+        }
+        impl<R: Read> Read for InterruptOnce<R> {
+            fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+                if !self.interrupted {
+                    self.interrupted = true;
+                    return Err(Error::from(ErrorKind::Interrupted));
+                }
+                self.inner.read(buf)
+            }
+        }
+        let reader = InterruptOnce { inner: Cursor::new(b"Hello, World!".to_vec()), interrupted: false };
+        assert_eq!(hash_reader(reader, HashAlgorithm::Sha256).unwrap(), file.hash());
+    }
+
+    #[test]
+    fn test_rename_to() {
+        let test_dir = setup_test_env("test_rename_to");
+        let file_path = test_dir.join("old.txt");
+        fs::write(&file_path, b"Hello, World!").unwrap();
+
+        let mut file = File::new(&file_path);
+        file.rename_to("new.txt").unwrap();
+
+        assert_eq!(file.path(), test_dir.join("new.txt"));
+        assert!(file.exists());
+        assert!(!file_path.exists());
+    }
+
+    #[test]
+    fn test_rename_to_rejects_separator() {
+        let test_dir = setup_test_env("test_rename_to_rejects_separator");
+        let file_path = test_dir.join("old.txt");
+        fs::write(&file_path, b"Hello, World!").unwrap();
+
+        let mut file = File::new(&file_path);
+        let err = file.rename_to("sub/new.txt").unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+        assert_eq!(file.path(), file_path);
+        assert!(file.exists());
+    }
+
+    #[test]
+    fn test_exists_as_file_and_dir() {
+        let test_dir = setup_test_env("test_exists_as_file_and_dir");
+        let file_path = test_dir.join("file.txt");
+        fs::File::create(&file_path).unwrap();
+
+        let file = File::new(&file_path);
+        let dir = File::new(&test_dir);
+        let missing = File::new(test_dir.join("missing.txt"));
+
+        assert!(file.exists_as_file());
+        assert!(!file.exists_as_dir());
+
+        assert!(dir.exists_as_dir());
+        assert!(!dir.exists_as_file());
+
+        assert!(!missing.exists_as_file());
+        assert!(!missing.exists_as_dir());
+    }
+
+    #[test]
+    fn test_tree_string() {
+        let test_dir = setup_test_env("test_tree_string");
+        fs::create_dir_all(test_dir.join("b_dir/nested")).unwrap();
+        fs::write(test_dir.join("c.txt"), b"c").unwrap();
+        fs::write(test_dir.join("a.txt"), b"a").unwrap();
+        fs::write(test_dir.join("b_dir/inner.txt"), b"inner").unwrap();
+
+        let tree = File::new(&test_dir).tree_string().unwrap();
+        let expected = format!(
+            "{}\n├── a.txt\n├── b_dir\n│   ├── inner.txt\n│   └── nested\n└── c.txt\n",
+            test_dir.display()
+        );
+        assert_eq!(tree, expected);
+
+        // 깊이를 1로 제한하면 하위 디렉터리의 내용은 포함되지 않아야 합니다.
+        let shallow = File::new(&test_dir).tree_string_depth(1).unwrap();
+        assert!(shallow.contains("b_dir"));
+        assert!(!shallow.contains("inner.txt"));
+
+        // 크기를 표시하면 파일에만 크기가 붙어야 합니다.
+        let sized = File::new(&test_dir).tree_string_with(usize::MAX, true).unwrap();
+        let expected = format!(
+            "{}\n├── a.txt (1 bytes)\n├── b_dir\n│   ├── inner.txt (5 bytes)\n│   └── nested\n└── c.txt (1 bytes)\n",
+            test_dir.display()
+        );
+        assert_eq!(sized, expected);
+    }
+
+    #[test]
+    fn test_copy_to_preserving() {
+        let test_dir = setup_test_env("test_copy_to_preserving");
+        let source_path = test_dir.join("source.txt");
+        let dest_path = test_dir.join("dest.txt");
+        fs::write(&source_path, b"Hello, World!").unwrap();
+
+        // 원본의 수정 시간을 과거로 설정하여 복사 시점과 구분되게 합니다.
+        let past = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+        fs::File::options()
+            .write(true)
+            .open(&source_path)
+            .unwrap()
+            .set_times(FileTimes::new().set_accessed(past).set_modified(past))
+            .unwrap();
+
+        let source = File::new(&source_path);
+        let copied = source.copy_to_preserving(&dest_path).unwrap();
+
+        assert!(copied.is_deep_match(&source));
+        assert_eq!(
+            copied.metadata().unwrap().modified().unwrap(),
+            source.metadata().unwrap().modified().unwrap()
+        );
+    }
+
+    #[test]
+    fn test_is_match_compares_digests() {
+        let test_dir = setup_test_env("test_is_match_compares_digests");
+        let file1_path = test_dir.join("file1.txt");
+        let file2_path = test_dir.join("file2.txt");
+        let file3_path = test_dir.join("file3.txt");
+        let file4_path = test_dir.join("file4.txt");
+
+        fs::write(&file1_path, b"Hello, World!").unwrap();
+        fs::write(&file2_path, b"Hello, World!").unwrap();
+        // 크기는 같지만 내용이 다른 파일입니다.
+        fs::write(&file3_path, b"Hello, Rust!!").unwrap();
+        fs::write(&file4_path, b"Hello").unwrap();
+
+        let file1 = File::new(&file1_path);
+
+        assert!(file1.is_match(&File::new(&file2_path)));
+        assert!(!file1.is_match(&File::new(&file3_path)));
+        assert!(!file1.is_match(&File::new(&file4_path)));
+        assert!(!file1.is_match(&File::new(&test_dir)));
+    }
+
+    #[test]
+    fn test_hash_limited() {
+        let test_dir = setup_test_env("test_hash_limited");
+        let file_path = test_dir.join("file.txt");
+        fs::write(&file_path, b"Hello, World!").unwrap();
+
+        let file = File::new(&file_path);
+        assert_eq!(file.hash_limited(13).unwrap(), file.hash());
+
+        let err = file.hash_limited(12).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::FileTooLarge);
+    }
+
+    // 테스트용으로 파일의 수정 시간을 지정한 시각으로 설정합니다.
+    fn set_mtime(path: &Path, time: SystemTime) {
+        fs::File::options()
+            .write(true)
+            .open(path)
+            .unwrap()
+            .set_modified(time)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_walk() {
+        let test_dir = setup_test_env("test_walk");
+        fs::create_dir_all(test_dir.join("sub")).unwrap();
+        fs::write(test_dir.join("a.txt"), b"a").unwrap();
+        fs::write(test_dir.join("sub/b.txt"), b"b").unwrap();
+
+        let entries = File::new(&test_dir).walk().unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                File::new(test_dir.join("a.txt")),
+                File::new(test_dir.join("sub")),
+                File::new(test_dir.join("sub/b.txt")),
+            ]
+        );
+
+        let err = File::new(test_dir.join("a.txt")).walk().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotADirectory);
+    }
+
+    #[test]
+    fn test_find_modified_between() {
+        let test_dir = setup_test_env("test_find_modified_between");
+        fs::create_dir_all(test_dir.join("sub")).unwrap();
+
+        let now = SystemTime::now();
+        let old_path = test_dir.join("old.log");
+        let inside_path = test_dir.join("sub/inside.log");
+        let recent_path = test_dir.join("recent.log");
+        fs::write(&old_path, b"old").unwrap();
+        fs::write(&inside_path, b"inside").unwrap();
+        fs::write(&recent_path, b"recent").unwrap();
+        set_mtime(&old_path, now - Duration::from_secs(300));
+        set_mtime(&inside_path, now - Duration::from_secs(200));
+        set_mtime(&recent_path, now - Duration::from_secs(100));
+
+        let dir = File::new(&test_dir);
+        let found = dir
+            .find_modified_between(now - Duration::from_secs(250), now - Duration::from_secs(150))
+            .unwrap();
+        assert_eq!(found, vec![File::new(&inside_path)]);
+
+        let err = File::new(&old_path).find_modified_between(now, now).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotADirectory);
+    }
+
+    #[test]
+    fn test_largest() {
+        let test_dir = setup_test_env("test_largest");
+        fs::create_dir_all(test_dir.join("sub")).unwrap();
+        fs::write(test_dir.join("small.txt"), vec![0u8; 10]).unwrap();
+        fs::write(test_dir.join("medium.txt"), vec![0u8; 100]).unwrap();
+        fs::write(test_dir.join("sub/large.txt"), vec![0u8; 1000]).unwrap();
+
+        let largest = File::new(&test_dir).largest(2).unwrap();
+        assert_eq!(
+            largest,
+            vec![
+                File::new(test_dir.join("sub/large.txt")),
+                File::new(test_dir.join("medium.txt")),
+            ]
+        );
+
+        assert!(File::new(&test_dir).largest(0).unwrap().is_empty());
+        assert_eq!(File::new(&test_dir).largest(10).unwrap().len(), 3);
+    }
+
+    #[test]
+    fn test_find_empty_dirs() {
+        let test_dir = setup_test_env("test_find_empty_dirs");
+        fs::create_dir_all(test_dir.join("empty/nested/deeper")).unwrap();
+        fs::create_dir_all(test_dir.join("full/empty_child")).unwrap();
+        fs::write(test_dir.join("full/file.txt"), b"content").unwrap();
+
+        let empty_dirs = File::new(&test_dir).find_empty_dirs().unwrap();
+        assert_eq!(
+            empty_dirs,
+            vec![
+                File::new(test_dir.join("empty")),
+                File::new(test_dir.join("empty/nested")),
+                File::new(test_dir.join("empty/nested/deeper")),
+                File::new(test_dir.join("full/empty_child")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_copy_into_existing_dir() {
+        let test_dir = setup_test_env("test_copy_into_existing_dir");
+        let source_path = test_dir.join("source.txt");
+        let target_dir = test_dir.join("target");
+        fs::write(&source_path, b"Hello, World!").unwrap();
+        fs::create_dir(&target_dir).unwrap();
+
+        let source = File::new(&source_path);
+        let copied = source.copy_into(&target_dir).unwrap();
+
+        assert_eq!(copied.path(), target_dir.join("source.txt"));
+        assert!(copied.is_deep_match(&source));
+    }
+
+    #[test]
+    fn test_copy_into_missing_dir() {
+        let test_dir = setup_test_env("test_copy_into_missing_dir");
+        let source_path = test_dir.join("source.txt");
+        let target_dir = test_dir.join("missing/target");
+        fs::write(&source_path, b"Hello, World!").unwrap();
+
+        let source = File::new(&source_path);
+        let copied = source.copy_into(&target_dir).unwrap();
+
+        assert!(target_dir.is_dir());
+        assert!(copied.is_deep_match(&source));
+
+        // 대상이 기존 파일이면 오류가 발생해야 합니다.
+        let err = source.copy_into(&source_path).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotADirectory);
+    }
+
+    #[test]
+    fn test_open_with() {
+        let test_dir = setup_test_env("test_open_with");
+        let file_path = test_dir.join("file.txt");
+        fs::write(&file_path, b"Hello").unwrap();
+
+        let file = File::new(&file_path);
+        let mut handle = file
+            .open_with(OpenOptions::new().read(true).write(true).append(true))
+            .unwrap();
+        handle.write_all(b", World!").unwrap();
+        drop(handle);
+
+        assert_eq!(fs::read(&file_path).unwrap(), b"Hello, World!");
+        assert!(File::new(test_dir.join("missing.txt")).open_with(OpenOptions::new().read(true)).is_err());
+    }
+
+    #[test]
+    fn test_to_slash_and_from_slash() {
+        let file = File::from_slash("a/b/c.txt");
+        assert_eq!(file.path(), Path::new("a").join("b").join("c.txt"));
+        assert_eq!(file.to_slash().unwrap(), "a/b/c.txt");
+
+        // 플랫폼 고유의 구분자로 만든 경로도 `/`로 변환되어야 합니다.
+        let native = File::new(Path::new("x").join("y").join("z.txt"));
+        assert_eq!(native.to_slash().unwrap(), "x/y/z.txt");
+        assert_eq!(File::from_slash(&native.to_slash().unwrap()), native);
+    }
+
+    #[test]
+    fn test_usage_by_extension() {
+        let test_dir = setup_test_env("test_usage_by_extension");
+        fs::create_dir_all(test_dir.join("sub")).unwrap();
+        fs::write(test_dir.join("a.txt"), vec![0u8; 10]).unwrap();
+        fs::write(test_dir.join("sub/b.TXT"), vec![0u8; 20]).unwrap();
+        fs::write(test_dir.join("app.log"), vec![0u8; 5]).unwrap();
+        fs::write(test_dir.join("Makefile"), vec![0u8; 7]).unwrap();
+
+        let usage = File::new(&test_dir).usage_by_extension().unwrap();
+        assert_eq!(usage.len(), 3);
+        assert_eq!(usage["txt"], 30);
+        assert_eq!(usage["log"], 5);
+        assert_eq!(usage[""], 7);
+    }
+
+    #[test]
+    fn test_append_locked() {
+        let test_dir = setup_test_env("test_append_locked");
+        let file_path = test_dir.join("log.txt");
+
+        let threads: Vec<_> = (0..8)
+            .map(|id| {
+                let file = File::new(&file_path);
+                std::thread::spawn(move || {
+                    for seq in 0..50 {
+                        let line = format!("{}-{}-{}\n", id, seq, "x".repeat(512));
+                        file.append_locked(line).unwrap();
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let content = fs::read_to_string(&file_path).unwrap();
+        let lines: Vec<&str> = content.lines().collect();
+        assert_eq!(lines.len(), 8 * 50);
+        for id in 0..8 {
+            for seq in 0..50 {
+                let expected = format!("{}-{}-{}", id, seq, "x".repeat(512));
+                assert!(lines.contains(&expected.as_str()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_lock_creates_file_and_keeps_content() {
+        let test_dir = setup_test_env("test_lock_creates_file_and_keeps_content");
+        let file_path = test_dir.join("lock.txt");
+        fs::write(&file_path, b"Hello, World!").unwrap();
+
+        let file = File::new(&file_path);
+        drop(file.lock().unwrap());
+        drop(file.lock_shared().unwrap());
+        assert_eq!(fs::read(&file_path).unwrap(), b"Hello, World!");
+
+        let missing = File::new(test_dir.join("new.lock"));
+        drop(missing.lock().unwrap());
+        assert!(missing.exists());
+    }
+
+    #[test]
+    fn test_write_atomic() {
+        let test_dir = setup_test_env("test_write_atomic");
+        let file_path = test_dir.join("config.txt");
+        fs::write(&file_path, b"old content").unwrap();
+
+        let file = File::new(&file_path);
+        file.write_atomic(b"new content").unwrap();
+        assert_eq!(fs::read(&file_path).unwrap(), b"new content");
+
+        // 임시 파일이 남아 있지 않아야 합니다.
+        assert_eq!(fs::read_dir(&test_dir).unwrap().count(), 1);
+
+        // 디렉터리가 없으면 실패하고 임시 파일도 남기지 않아야 합니다.
+        assert!(File::new(test_dir.join("missing/config.txt")).write_atomic(b"data").is_err());
+    }
+
+    #[test]
+    fn test_write_modes_on_existing_file() {
+        let test_dir = setup_test_env("test_write_modes_on_existing_file");
+        let file_path = test_dir.join("file.txt");
+        let file = File::new(&file_path);
+
+        file.create_new(b"first").unwrap();
+        assert_eq!(fs::read(&file_path).unwrap(), b"first");
+
+        // create_new는 기존 파일을 덮어쓰지 않아야 합니다.
+        let err = file.create_new(b"second").unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+        assert_eq!(fs::read(&file_path).unwrap(), b"first");
+
+        // append는 기존 내용 뒤에 이어서 기록해야 합니다.
+        file.append(b", second").unwrap();
+        assert_eq!(fs::read(&file_path).unwrap(), b"first, second");
+
+        // create_or_truncate는 기존 내용을 비우고 새로 기록해야 합니다.
+        file.create_or_truncate(b"third").unwrap();
+        assert_eq!(fs::read(&file_path).unwrap(), b"third");
+    }
+
+    #[test]
+    fn test_rm_retry_first_attempt() {
+        let test_dir = setup_test_env("test_rm_retry_first_attempt");
+        let file_path = test_dir.join("file.txt");
+        fs::write(&file_path, b"Hello, World!").unwrap();
+
+        // 삭제 가능한 파일은 대기 없이 첫 시도에 삭제되어야 합니다.
+        let started = std::time::Instant::now();
+        File::new(&file_path).rm_retry(5, Duration::from_secs(10)).unwrap();
+        assert!(started.elapsed() < Duration::from_secs(10));
+        assert!(!file_path.exists());
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn test_rm_retry_waits_for_lock() {
+        use std::os::windows::fs::OpenOptionsExt;
+
+        let test_dir = setup_test_env("test_rm_retry_waits_for_lock");
+        let file_path = test_dir.join("locked.txt");
+        fs::write(&file_path, b"Hello, World!").unwrap();
+
+        // 공유 모드 없이 연 핸들이 닫힐 때까지 삭제가 거부되다가 이후 성공해야 합니다.
+        let handle = OpenOptions::new().read(true).share_mode(0).open(&file_path).unwrap();
+        let releaser = std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(200));
+            drop(handle);
+        });
+
+        File::new(&file_path).rm_retry(10, Duration::from_millis(50)).unwrap();
+        releaser.join().unwrap();
+        assert!(!file_path.exists());
+    }
+
+    #[test]
+    fn test_common_ancestor() {
+        let files = [
+            File::new("/data/a/b/one.txt"),
+            File::new("/data/a/b/c/two.txt"),
+            File::new("/data/a/b/three.txt"),
+        ];
+        assert_eq!(common_ancestor(&files), Some(File::new("/data/a/b")));
+
+        // 루트만 공유하는 경우 루트를 반환해야 합니다.
+        let files = [File::new("/usr/bin/ls"), File::new("/etc/hosts")];
+        assert_eq!(common_ancestor(&files), Some(File::new("/")));
+
+        // 공유하는 구성 요소가 없거나 입력이 비어 있으면 None을 반환해야 합니다.
+        assert_eq!(common_ancestor(&[File::new("a/b"), File::new("c/d")]), None);
+        assert_eq!(common_ancestor(&[]), None);
+    }
+
+    #[test]
+    fn test_copy_contents_to() {
+        let test_dir = setup_test_env("test_copy_contents_to");
+        let source_path = test_dir.join("source.bin");
+        let dest_path = test_dir.join("dest.bin");
+        let content: Vec<u8> = (0..200_000u32).map(|i| (i % 251) as u8).collect();
+        fs::write(&source_path, &content).unwrap();
+        fs::write(&dest_path, b"stale content that should be replaced").unwrap();
+
+        let source = File::new(&source_path);
+        let dest = File::new(&dest_path);
+        let copied = source.copy_contents_to(&dest).unwrap();
+
+        assert_eq!(copied, source.len().unwrap());
+        assert!(dest.is_deep_match(&source));
+
+        let err = File::new(&test_dir).copy_contents_to(&dest).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::IsADirectory);
+    }
+
+    #[test]
+    fn test_find_case_conflicts() {
+        let test_dir = setup_test_env("test_find_case_conflicts");
+        fs::create_dir_all(test_dir.join("sub")).unwrap();
+        fs::write(test_dir.join("README.md"), b"upper").unwrap();
+        fs::write(test_dir.join("readme.md"), b"lower").unwrap();
+        fs::write(test_dir.join("unique.txt"), b"unique").unwrap();
+        // 서로 다른 디렉터리에 있는 같은 이름은 충돌하지 않습니다.
+        fs::write(test_dir.join("sub/Unique.txt"), b"unique").unwrap();
+
+        let conflicts = File::new(&test_dir).find_case_conflicts().unwrap();
+        assert_eq!(
+            conflicts,
+            vec![vec![File::new(test_dir.join("README.md")), File::new(test_dir.join("readme.md"))]]
+        );
+    }
+
+    #[test]
+    fn test_chunks() {
+        let test_dir = setup_test_env("test_chunks");
+        let source_path = test_dir.join("source.bin");
+        let rebuilt_path = test_dir.join("rebuilt.bin");
+        let content: Vec<u8> = (0..1000u32).map(|i| (i % 251) as u8).collect();
+        fs::write(&source_path, &content).unwrap();
+
+        let source = File::new(&source_path);
+        let chunks: Vec<Vec<u8>> = source.chunks(300).unwrap().collect::<Result<_>>().unwrap();
+
+        assert_eq!(chunks.iter().map(Vec::len).collect::<Vec<_>>(), vec![300, 300, 300, 100]);
+        assert_eq!(chunks.iter().map(|c| c.len() as u64).sum::<u64>(), source.len().unwrap());
+
+        fs::write(&rebuilt_path, chunks.concat()).unwrap();
+        assert!(File::new(&rebuilt_path).is_deep_match(&source));
+
+        assert_eq!(source.chunks(0).err().unwrap().kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_manifest_diff() {
+        let test_dir = setup_test_env("test_manifest_diff");
+        fs::create_dir_all(test_dir.join("sub")).unwrap();
+        fs::write(test_dir.join("a.txt"), b"a").unwrap();
+        fs::write(test_dir.join("sub/b.txt"), b"b").unwrap();
+
+        let root = File::new(&test_dir);
+        let old = manifest(&root, HashAlgorithm::Sha256).unwrap();
+        assert_eq!(
+            old.keys().cloned().collect::<Vec<_>>(),
+            vec![PathBuf::from("a.txt"), Path::new("sub").join("b.txt")]
+        );
+
+        fs::write(test_dir.join("sub/b.txt"), b"modified").unwrap();
+        let new = manifest(&root, HashAlgorithm::Sha256).unwrap();
+
+        let diff = diff_manifest(&old, &new);
+        assert_eq!(
+            diff,
+            ManifestDiff {
+                added: vec![],
+                removed: vec![],
+                changed: vec![Path::new("sub").join("b.txt")],
+            }
+        );
+
+        // 파일 추가와 삭제도 보고되어야 합니다.
+        fs::remove_file(test_dir.join("a.txt")).unwrap();
+        fs::write(test_dir.join("c.txt"), b"c").unwrap();
+        let latest = manifest(&root, HashAlgorithm::Sha256).unwrap();
+        let diff = diff_manifest(&new, &latest);
+        assert_eq!(diff.added, vec![PathBuf::from("c.txt")]);
+        assert_eq!(diff.removed, vec![PathBuf::from("a.txt")]);
+        assert!(diff.changed.is_empty());
+    }
+
+    #[test]
+    fn test_read_lines() {
+        let test_dir = setup_test_env("test_read_lines");
+        let lf_path = test_dir.join("lf.txt");
+        let crlf_path = test_dir.join("crlf.txt");
+        let blank_path = test_dir.join("blank.txt");
+        let empty_path = test_dir.join("empty.txt");
+        fs::write(&lf_path, b"one\ntwo\nthree").unwrap();
+        fs::write(&crlf_path, b"one\r\ntwo\r\nthree\r\n").unwrap();
+        fs::write(&blank_path, b"one\ntwo\n\n").unwrap();
+        fs::write(&empty_path, b"").unwrap();
+
+        assert_eq!(File::new(&lf_path).read_lines().unwrap(), vec!["one", "two", "three"]);
+        assert_eq!(File::new(&crlf_path).read_lines().unwrap(), vec!["one", "two", "three"]);
+        // 마지막 줄바꿈으로 인한 빈 조각만 제거되고 실제 빈 줄은 유지되어야 합니다.
+        assert_eq!(File::new(&blank_path).read_lines().unwrap(), vec!["one", "two", ""]);
+        assert!(File::new(&empty_path).read_lines().unwrap().is_empty());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_same_file() {
+        let test_dir = setup_test_env("test_same_file");
+        let target_path = test_dir.join("target.txt");
+        let link_path = test_dir.join("link.txt");
+        let other_path = test_dir.join("other.txt");
+        fs::write(&target_path, b"Hello, World!").unwrap();
+        fs::write(&other_path, b"Hello, World!").unwrap();
+        fs::create_dir(test_dir.join("sub")).unwrap();
+        std::os::unix::fs::symlink(&target_path, &link_path).unwrap();
+
+        let target = File::new(&target_path);
+        assert!(File::new(&link_path).same_file(&target).unwrap());
+        assert!(File::new(test_dir.join("sub/../target.txt")).same_file(&target).unwrap());
+        // 내용이 같더라도 다른 파일이면 false를 반환해야 합니다.
+        assert!(!File::new(&other_path).same_file(&target).unwrap());
+    }
+
+    #[test]
+    fn test_bytes_equal_at() {
+        let test_dir = setup_test_env("test_bytes_equal_at");
+        let file1_path = test_dir.join("file1.bin");
+        let file2_path = test_dir.join("file2.bin");
+        fs::write(&file1_path, b"0123456789abcdef").unwrap();
+        fs::write(&file2_path, b"0123456789ABCDEF").unwrap();
+
+        let file1 = File::new(&file1_path);
+        let file2 = File::new(&file2_path);
+
+        assert!(file1.bytes_equal_at(&file2, 2, 8).unwrap());
+        assert!(!file1.bytes_equal_at(&file2, 8, 4).unwrap());
+        // 파일 끝을 넘는 범위는 false를 반환해야 합니다.
+        assert!(!file1.bytes_equal_at(&file1, 10, 10).unwrap());
+        assert!(file1.bytes_equal_at(&file2, 0, 0).unwrap());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_blocksize() {
+        let test_dir = setup_test_env("test_blocksize");
+        let file_path = test_dir.join("file.txt");
+        fs::write(&file_path, b"Hello, World!").unwrap();
+
+        let blocksize = File::new(&file_path).blocksize().unwrap();
+        assert!(blocksize.is_power_of_two());
+        assert!((512..=16 * 1024 * 1024).contains(&blocksize));
+
+        assert!(File::new(test_dir.join("missing.txt")).blocksize().is_err());
+    }
+
+    #[test]
+    fn test_mkdirs() {
+        let test_dir = setup_test_env("test_mkdirs");
+        let dir = File::new(test_dir.join("a/b/c"));
+
+        dir.mkdirs().unwrap();
+        assert!(dir.is_directory());
+        // 이미 존재하는 디렉터리에 다시 호출해도 오류가 없어야 합니다.
+        dir.mkdirs().unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_mkdirs_mode() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let test_dir = setup_test_env("test_mkdirs_mode");
+        let dir = File::new(test_dir.join("private/nested"));
+
+        dir.mkdirs_mode(0o700).unwrap();
+        assert!(dir.is_directory());
+        assert_eq!(dir.metadata().unwrap().permissions().mode() & 0o777, 0o700);
+        assert_eq!(fs::metadata(test_dir.join("private")).unwrap().permissions().mode() & 0o777, 0o700);
+    }
+
+    #[test]
+    fn test_first_line() {
+        let test_dir = setup_test_env("test_first_line");
+        let multi_path = test_dir.join("multi.csv");
+        let empty_path = test_dir.join("empty.txt");
+        let single_path = test_dir.join("single.txt");
+        fs::write(&multi_path, b"id,name\r\n1,foo\n2,bar\n").unwrap();
+        fs::write(&empty_path, b"").unwrap();
+        fs::write(&single_path, b"{\"key\": 1}").unwrap();
+
+        assert_eq!(File::new(&multi_path).first_line().unwrap(), Some("id,name".to_string()));
+        assert_eq!(File::new(&empty_path).first_line().unwrap(), None);
+        assert_eq!(File::new(&single_path).first_line().unwrap(), Some("{\"key\": 1}".to_string()));
+    }
+
+    #[test]
+    fn test_tree_stats() {
+        let test_dir = setup_test_env("test_tree_stats");
+        fs::create_dir_all(test_dir.join("a/b")).unwrap();
+        fs::write(test_dir.join("one.txt"), vec![0u8; 10]).unwrap();
+        fs::write(test_dir.join("a/two.txt"), vec![0u8; 300]).unwrap();
+        fs::write(test_dir.join("a/b/three.txt"), vec![0u8; 20]).unwrap();
+
+        let stats = File::new(&test_dir).tree_stats().unwrap();
+        assert_eq!(
+            stats,
+            TreeStats {
+                file_count: 3,
+                dir_count: 2,
+                total_bytes: 330,
+                largest_file: Some(File::new(test_dir.join("a/two.txt"))),
+            }
+        );
+    }
+
+    #[test]
+    fn test_with_buffer_size() {
+        let test_dir = setup_test_env("test_with_buffer_size");
+        let file_path = test_dir.join("file.bin");
+        let content: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+        fs::write(&file_path, &content).unwrap();
+
+        let expected = File::new(&file_path).hash();
+        assert_eq!(File::new(&file_path).with_buffer_size(7).hash(), expected);
+        // 0을 지정해도 기본 버퍼 크기로 동작해야 합니다.
+        assert_eq!(File::new(&file_path).with_buffer_size(0).hash(), expected);
+
+        // 버퍼 크기는 동등성 비교에 영향을 주지 않아야 합니다.
+        assert_eq!(File::new(&file_path).with_buffer_size(7), File::new(&file_path));
+    }
+
+    #[test]
+    fn test_positional_io() {
+        let test_dir = setup_test_env("test_positional_io");
+        let file_path = test_dir.join("records.bin");
+        fs::write(&file_path, vec![0u8; 200]).unwrap();
+
+        let file = File::new(&file_path);
+        file.write_all_at(b"record", 100).unwrap();
+        assert_eq!(file.len().unwrap(), 200);
+
+        let mut buf = [0u8; 6];
+        file.read_exact_at(&mut buf, 100).unwrap();
+        assert_eq!(&buf, b"record");
+
+        // 파일 끝을 넘어 읽으면 UnexpectedEof 오류가 발생해야 합니다.
+        let err = file.read_exact_at(&mut buf, 198).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_find_duplicates() {
+        let test_dir = setup_test_env("test_find_duplicates");
+        fs::create_dir_all(test_dir.join("sub")).unwrap();
+        fs::write(test_dir.join("a.bin"), vec![7u8; 1024]).unwrap();
+        fs::write(test_dir.join("sub/b.bin"), vec![7u8; 1024]).unwrap();
+        // 크기는 같지만 내용이 다른 파일은 중복이 아닙니다.
+        fs::write(test_dir.join("c.bin"), vec![8u8; 1024]).unwrap();
+        fs::write(test_dir.join("d.bin"), vec![7u8; 10]).unwrap();
+
+        let report = File::new(&test_dir).find_duplicates().unwrap();
+        assert_eq!(
+            report.groups,
+            vec![DuplicateGroup {
+                size: 1024,
+                files: vec![File::new(test_dir.join("a.bin")), File::new(test_dir.join("sub/b.bin"))],
+            }]
+        );
+        assert_eq!(report.wasted_bytes, 1024);
+    }
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*.rs", "main.rs"));
+        assert!(!glob_match("*.rs", "main.rs.bak"));
+        assert!(glob_match("file?.txt", "file1.txt"));
+        assert!(!glob_match("file?.txt", "file10.txt"));
+        assert!(glob_match("[abc]*.log", "b_server.log"));
+        assert!(glob_match("v[0-9].txt", "v7.txt"));
+        assert!(!glob_match("v[!0-9].txt", "v7.txt"));
+        assert!(!glob_match("*.TXT", "notes.txt"));
+    }
+
+    #[test]
+    fn test_glob() {
+        let test_dir = setup_test_env("test_glob");
+        fs::create_dir_all(test_dir.join("sub")).unwrap();
+        fs::write(test_dir.join("a.txt"), b"a").unwrap();
+        fs::write(test_dir.join("b.log"), b"b").unwrap();
+        fs::write(test_dir.join("sub/c.txt"), b"c").unwrap();
+
+        let matches = File::new(&test_dir).glob("*.txt").unwrap();
+        assert_eq!(matches, vec![File::new(test_dir.join("a.txt"))]);
+    }
+
+    #[test]
+    fn test_glob_recursive() {
+        let test_dir = setup_test_env("test_glob_recursive");
+        fs::create_dir_all(test_dir.join("src/nested/deep")).unwrap();
+        fs::write(test_dir.join("top.txt"), b"top").unwrap();
+        fs::write(test_dir.join("src/lib.rs"), b"lib").unwrap();
+        fs::write(test_dir.join("src/nested/mid.txt"), b"mid").unwrap();
+        fs::write(test_dir.join("src/nested/deep/mod.rs"), b"mod").unwrap();
+
+        let dir = File::new(&test_dir);
+        assert_eq!(
+            dir.glob_recursive("**/*.txt").unwrap(),
+            vec![File::new(test_dir.join("src/nested/mid.txt")), File::new(test_dir.join("top.txt"))]
+        );
+        assert_eq!(
+            dir.glob_recursive("src/**/*.rs").unwrap(),
+            vec![File::new(test_dir.join("src/lib.rs")), File::new(test_dir.join("src/nested/deep/mod.rs"))]
+        );
+        // `**`가 없으면 최상위 항목만 일치해야 합니다.
+        assert_eq!(dir.glob_recursive("*.txt").unwrap(), vec![File::new(test_dir.join("top.txt"))]);
+    }
+
+    #[test]
+    fn test_canonical_or_self() {
+        let test_dir = setup_test_env("test_canonical_or_self");
+        fs::create_dir_all(test_dir.join("sub")).unwrap();
+        fs::write(test_dir.join("file.txt"), b"Hello, World!").unwrap();
+
+        let existing = File::new(test_dir.join("sub/../file.txt"));
+        assert_eq!(
+            existing.canonical_or_self(),
+            File::new(test_dir.join("file.txt").canonicalize().unwrap())
+        );
+
+        let missing = File::new(test_dir.join("sub/../missing.txt"));
+        assert_eq!(missing.canonical_or_self(), missing);
+    }
+
+    #[test]
+    fn test_is_match_ignoring() {
+        let test_dir = setup_test_env("test_is_match_ignoring");
+        let file1_path = test_dir.join("build1.bin");
+        let file2_path = test_dir.join("build2.bin");
+        let file3_path = test_dir.join("build3.bin");
+        fs::write(&file1_path, b"HEADER-20240101-PAYLOAD").unwrap();
+        fs::write(&file2_path, b"HEADER-20251231-PAYLOAD").unwrap();
+        fs::write(&file3_path, b"HEADER-20251231-PAYLOAD-EXTRA").unwrap();
+
+        let file1 = File::new(&file1_path);
+        let file2 = File::new(&file2_path);
+        let file3 = File::new(&file3_path);
+
+        let timestamp = 7..15;
+        assert!(file1.is_match_ignoring(&file2, std::slice::from_ref(&timestamp)).unwrap());
+        assert!(!file1.is_match_ignoring(&file2, &[7..10, 20..23]).unwrap());
+        // 무시 범위 밖에서 길이가 다르면 일치하지 않아야 합니다.
+        assert!(!file1.is_match_ignoring(&file3, std::slice::from_ref(&timestamp)).unwrap());
+        assert!(file1.is_match_ignoring(&file3, &[timestamp, 23..29]).unwrap());
+    }
+
+    #[test]
+    fn test_set_times() {
+        let test_dir = setup_test_env("test_set_times");
+        let file_path = test_dir.join("file.txt");
+        fs::write(&file_path, b"Hello, World!").unwrap();
+
+        let file = File::new(&file_path);
+        let epoch_time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000_000);
+        file.set_modified(epoch_time).unwrap();
+        assert_eq!(file.metadata().unwrap().modified().unwrap(), epoch_time);
+
+        // 미래 시각도 설정할 수 있어야 합니다.
+        let accessed = SystemTime::UNIX_EPOCH + Duration::from_secs(1_500_000_000);
+        let future = SystemTime::now() + Duration::from_secs(86_400 * 365);
+        file.set_times(accessed, future).unwrap();
+        let metadata = file.metadata().unwrap();
+        assert_eq!(metadata.accessed().unwrap(), accessed);
+        assert_eq!(metadata.modified().unwrap(), future);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_create_preallocated() {
+        let test_dir = setup_test_env("test_create_preallocated");
+        let file_path = test_dir.join("prealloc.bin");
+        fs::write(&file_path, b"stale content").unwrap();
+
+        let file = File::new(&file_path);
+        let mut handle = file.create_preallocated(4096).unwrap();
+        assert_eq!(file.len().unwrap(), 4096);
+
+        handle.write_all(b"header").unwrap();
+        drop(handle);
+        let content = fs::read(&file_path).unwrap();
+        assert_eq!(content.len(), 4096);
+        assert_eq!(&content[..6], b"header");
+        assert!(content[6..].iter().all(|&b| b == 0));
+
+        // 크기가 0이면 기존 내용을 비운 빈 파일이 되어야 합니다.
+        fs::write(&file_path, b"stale content").unwrap();
+        file.create_preallocated(0).unwrap();
+        assert_eq!(file.len().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_is_writable() {
+        let test_dir = setup_test_env("test_is_writable");
+        let writable_path = test_dir.join("writable.txt");
+        let readonly_path = test_dir.join("readonly.txt");
+        fs::write(&writable_path, b"data").unwrap();
+        fs::write(&readonly_path, b"data").unwrap();
+
+        let mut permissions = fs::metadata(&readonly_path).unwrap().permissions();
+        permissions.set_readonly(true);
+        fs::set_permissions(&readonly_path, permissions).unwrap();
+
+        assert!(File::new(&writable_path).is_writable());
+        assert!(File::new(test_dir.join("new.txt")).is_writable());
+        assert!(!File::new(test_dir.join("missing/new.txt")).is_writable());
+
+        // root는 권한 비트와 관계없이 쓸 수 있으므로 결과가 달라집니다.
+        #[cfg(unix)]
+        let privileged = unsafe { libc::geteuid() } == 0;
+        #[cfg(not(unix))]
+        let privileged = false;
+        assert_eq!(File::new(&readonly_path).is_writable(), privileged);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+
+            let locked_dir = test_dir.join("locked");
+            fs::create_dir(&locked_dir).unwrap();
+            fs::set_permissions(&locked_dir, fs::Permissions::from_mode(0o555)).unwrap();
+            assert_eq!(File::new(locked_dir.join("new.txt")).is_writable(), privileged);
+            fs::set_permissions(&locked_dir, fs::Permissions::from_mode(0o755)).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_copy_range_to() {
+        let test_dir = setup_test_env("test_copy_range_to");
+        let source_path = test_dir.join("container.bin");
+        let content: Vec<u8> = (0..1000u32).map(|i| (i % 251) as u8).collect();
+        fs::write(&source_path, &content).unwrap();
+
+        let source = File::new(&source_path);
+        let middle = source.copy_range_to(450, 100, test_dir.join("middle.bin")).unwrap();
+        assert_eq!(middle.len().unwrap(), 100);
+        assert_eq!(fs::read(middle.path()).unwrap(), &content[450..550]);
+
+        // 파일 끝을 넘는 범위는 남은 바이트만 복사해야 합니다.
+        let tail = source.copy_range_to(950, 100, test_dir.join("tail.bin")).unwrap();
+        assert_eq!(fs::read(tail.path()).unwrap(), &content[950..]);
+    }
+
+    #[test]
+    fn test_first_difference() {
+        let test_dir = setup_test_env("test_first_difference");
+        let base_path = test_dir.join("base.txt");
+        let same_path = test_dir.join("same.txt");
+        let diff_path = test_dir.join("diff.txt");
+        let prefix_path = test_dir.join("prefix.txt");
+        fs::write(&base_path, b"Hello, World!").unwrap();
+        fs::write(&same_path, b"Hello, World!").unwrap();
+        fs::write(&diff_path, b"Hello; World!").unwrap();
+        fs::write(&prefix_path, b"Hello").unwrap();
+
+        let base = File::new(&base_path);
+        assert_eq!(base.first_difference(&File::new(&same_path)).unwrap(), None);
+        assert_eq!(base.first_difference(&File::new(&diff_path)).unwrap(), Some(5));
+        assert_eq!(base.first_difference(&File::new(&prefix_path)).unwrap(), Some(5));
+        assert_eq!(File::new(&prefix_path).first_difference(&base).unwrap(), Some(5));
+
+        // 버퍼 경계를 넘는 위치의 차이도 정확히 찾아야 합니다.
+        let small_buffer = File::new(&base_path).with_buffer_size(3);
+        assert_eq!(small_buffer.first_difference(&File::new(&diff_path)).unwrap(), Some(5));
+    }
+
+    #[test]
+    fn test_read_utf8_lossy() {
+        let test_dir = setup_test_env("test_read_utf8_lossy");
+        let file_path = test_dir.join("broken.log");
+        fs::write(&file_path, b"line one\n\xff\xfe bad bytes\n").unwrap();
+
+        let file = File::new(&file_path);
+        assert_eq!(file.read_to_string().unwrap_err().kind(), ErrorKind::InvalidData);
+        assert_eq!(file.read_utf8_lossy().unwrap(), "line one\n\u{FFFD}\u{FFFD} bad bytes\n");
+    }
+
+    #[test]
+    fn test_structure_hash() {
+        let test_dir = setup_test_env("test_structure_hash");
+        fs::create_dir_all(test_dir.join("sub")).unwrap();
+        fs::write(test_dir.join("a.txt"), b"a").unwrap();
+        fs::write(test_dir.join("sub/b.txt"), b"b").unwrap();
+
+        let dir = File::new(&test_dir);
+        let original = dir.structure_hash().unwrap();
+
+        // 내용만 바뀌면 구조 해시는 그대로여야 합니다.
+        fs::write(test_dir.join("a.txt"), b"modified content").unwrap();
+        assert_eq!(dir.structure_hash().unwrap(), original);
+
+        // 파일이 추가되면 구조 해시가 바뀌어야 합니다.
+        fs::write(test_dir.join("sub/c.txt"), b"c").unwrap();
+        assert_ne!(dir.structure_hash().unwrap(), original);
+    }
+
+    #[test]
+    fn test_move_to() {
+        let test_dir = setup_test_env("test_move_to");
+        let source_path = test_dir.join("source.txt");
+        let dest_path = test_dir.join("dest.txt");
+        fs::write(&source_path, b"Hello, World!").unwrap();
+
+        let mut file = File::new(&source_path);
+        file.move_to(&dest_path).unwrap();
+
+        assert_eq!(file.path(), dest_path);
+        assert!(!source_path.exists());
+        assert_eq!(fs::read(&dest_path).unwrap(), b"Hello, World!");
+    }
+
+    #[test]
+    fn test_move_into_existing_dir() {
+        let test_dir = setup_test_env("test_move_into_existing_dir");
+        let source_path = test_dir.join("source.txt");
+        let target_dir = test_dir.join("target");
+        fs::write(&source_path, b"Hello, World!").unwrap();
+        fs::create_dir(&target_dir).unwrap();
+
+        let mut file = File::new(&source_path);
+        file.move_into(&target_dir).unwrap();
+
+        assert_eq!(file.path(), target_dir.join("source.txt"));
+        assert!(file.exists());
+        assert!(!source_path.exists());
+    }
+
+    #[test]
+    fn test_move_into_refuses_overwrite() {
+        let test_dir = setup_test_env("test_move_into_refuses_overwrite");
+        let source_path = test_dir.join("source.txt");
+        let target_dir = test_dir.join("target");
+        fs::write(&source_path, b"new").unwrap();
+        fs::create_dir(&target_dir).unwrap();
+        fs::write(target_dir.join("source.txt"), b"old").unwrap();
+
+        // 같은 이름의 파일이 있으면 이동하지 않고 두 파일 모두 그대로 남아야 합니다.
+        let mut file = File::new(&source_path);
+        assert_eq!(file.move_into(&target_dir).unwrap_err().kind(), ErrorKind::AlreadyExists);
+        assert_eq!(file.path(), source_path);
+        assert_eq!(fs::read(&source_path).unwrap(), b"new");
+        assert_eq!(fs::read(target_dir.join("source.txt")).unwrap(), b"old");
+    }
+
+    #[test]
+    fn test_move_into_missing_dir() {
+        let test_dir = setup_test_env("test_move_into_missing_dir");
+        let source_path = test_dir.join("source.txt");
+        let target_dir = test_dir.join("missing/target");
+        fs::write(&source_path, b"Hello, World!").unwrap();
+
+        let mut file = File::new(&source_path);
+        file.move_into(&target_dir).unwrap();
+
+        assert!(target_dir.is_dir());
+        assert_eq!(fs::read(target_dir.join("source.txt")).unwrap(), b"Hello, World!");
+        assert!(!source_path.exists());
+    }
+
+    #[test]
+    fn test_hash_with_case() {
+        let test_dir = setup_test_env("test_hash_with_case");
+        let file_path = test_dir.join("file.txt");
+        fs::write(&file_path, b"Hello, World!").unwrap();
+
+        let file = File::new(&file_path);
+        let lower = file.hash_with_case(HashAlgorithm::Sha256, false).unwrap();
+        let upper = file.hash_with_case(HashAlgorithm::Sha256, true).unwrap();
+
+        assert_eq!(lower, file.hash());
+        assert_eq!(upper, lower.to_uppercase());
+        assert_ne!(upper, lower);
+    }
+
+    #[test]
+    fn test_read_bytes_limited() {
+        let test_dir = setup_test_env("test_read_bytes_limited");
+        let file_path = test_dir.join("file.txt");
+        fs::write(&file_path, b"Hello, World!").unwrap();
+
+        let file = File::new(&file_path);
+        assert_eq!(file.read_bytes().unwrap(), b"Hello, World!");
+        assert_eq!(file.read_bytes_limited(13).unwrap(), b"Hello, World!");
+
+        let err = file.read_bytes_limited(12).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::FileTooLarge);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_sync_dir() {
+        let test_dir = setup_test_env("test_sync_dir");
+        let file_path = test_dir.join("file.txt");
+        File::new(&file_path).write_atomic(b"Hello, World!").unwrap();
+
+        File::new(&test_dir).sync_dir().unwrap();
+
+        let err = File::new(&file_path).sync_dir().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotADirectory);
+    }
+
+    #[test]
+    fn test_is_inside() {
+        let test_dir = setup_test_env("test_is_inside");
+        let root_path = test_dir.join("root");
+        fs::create_dir_all(root_path.join("child")).unwrap();
+        fs::create_dir_all(test_dir.join("sibling")).unwrap();
+
+        let root = File::new(&root_path);
+        assert!(File::new(root_path.join("child")).is_inside(&root).unwrap());
+        assert!(File::new(&root_path).is_inside(&root).unwrap());
+        assert!(!File::new(test_dir.join("sibling")).is_inside(&root).unwrap());
+        // `..`로 루트를 벗어나려는 경로는 포함되지 않아야 합니다.
+        assert!(!File::new(root_path.join("child/../../sibling")).is_inside(&root).unwrap());
+        // 존재하지 않는 경로도 어휘적으로 판단해야 합니다.
+        assert!(File::new(root_path.join("new/file.txt")).is_inside(&root).unwrap());
+        assert!(!File::new(root_path.join("new/../../escape.txt")).is_inside(&root).unwrap());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_is_inside_symlink_parent_escape() {
+        let test_dir = setup_test_env("test_is_inside_symlink_parent_escape");
+        let root_path = test_dir.join("root");
+        fs::create_dir_all(&root_path).unwrap();
+        fs::create_dir_all(test_dir.join("outside/sub")).unwrap();
+        std::os::unix::fs::symlink(test_dir.join("outside/sub"), root_path.join("link")).unwrap();
+        let root = File::new(&root_path);
+
+        // `link/..`는 운영체제가 링크 대상의 상위(outside)로 해석하므로 루트 밖이어야 합니다.
+        assert!(!File::new(root_path.join("link/../secret")).is_inside(&root).unwrap());
+        fs::write(test_dir.join("outside/secret"), b"secret").unwrap();
+        assert!(!File::new(root_path.join("link/../secret")).is_inside(&root).unwrap());
+
+        // 링크 안쪽 경로는 대상 위치로 해석되어 역시 루트 밖입니다.
+        assert!(!File::new(root_path.join("link/new.txt")).is_inside(&root).unwrap());
+    }
+
+    #[test]
+    fn test_copy_to_with_policy() {
+        let test_dir = setup_test_env("test_copy_to_with_policy");
+        let source_path = test_dir.join("source.txt");
+        let dest_path = test_dir.join("dest.txt");
+        fs::write(&source_path, b"new").unwrap();
+        fs::write(&dest_path, b"old").unwrap();
+
+        let source = File::new(&source_path);
+
+        // Skip: 기존 파일을 그대로 둡니다.
+        let skipped = source.copy_to_with_policy(&dest_path, OverwritePolicy::Skip).unwrap();
+        assert_eq!(skipped.path(), dest_path);
+        assert_eq!(fs::read(&dest_path).unwrap(), b"old");
+
+        // Error: AlreadyExists 오류를 반환하고 기존 파일을 유지합니다.
+        let err = source.copy_to_with_policy(&dest_path, OverwritePolicy::Error).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+        assert_eq!(fs::read(&dest_path).unwrap(), b"old");
+
+        // Rename: 확장자 앞에 번호를 붙인 이름으로 복사합니다.
+        let renamed = source.copy_to_with_policy(&dest_path, OverwritePolicy::Rename).unwrap();
+        assert_eq!(renamed.path(), test_dir.join("dest (1).txt"));
+        let renamed = source.copy_to_with_policy(&dest_path, OverwritePolicy::Rename).unwrap();
+        assert_eq!(renamed.path(), test_dir.join("dest (2).txt"));
+        assert_eq!(fs::read(&dest_path).unwrap(), b"old");
+
+        // Overwrite: 기존 파일을 덮어씁니다.
+        source.copy_to_with_policy(&dest_path, OverwritePolicy::Overwrite).unwrap();
+        assert_eq!(fs::read(&dest_path).unwrap(), b"new");
+    }
+
+    #[test]
+    fn test_read_records() {
+        let test_dir = setup_test_env("test_read_records");
+        let file_path = test_dir.join("records.bin");
+        let mut content = b"HEAD".to_vec();
+        for value in [1u32, 2, 0xDEADBEEF, 42] {
+            content.extend_from_slice(&value.to_le_bytes());
+        }
+        fs::write(&file_path, &content).unwrap();
+
+        let file = File::new(&file_path);
+        assert_eq!(file.read_records::<u32>(4, 4).unwrap(), vec![1, 2, 0xDEADBEEF, 42]);
+        assert_eq!(file.read_records::<u16>(4, 2).unwrap(), vec![1, 0]);
+
+        // 레코드가 모자라면 UnexpectedEof 오류가 발생해야 합니다.
+        let err = file.read_records::<u32>(6, 4).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::UnexpectedEof);
+    }
+
+    #[test]
+    fn test_ensure_extension() {
+        assert_eq!(File::new("out/image").ensure_extension("png"), File::new("out/image.png"));
+        assert_eq!(File::new("out/image.png").ensure_extension("png"), File::new("out/image.png"));
+        assert_eq!(File::new("out/image.PNG").ensure_extension(".png"), File::new("out/image.PNG"));
+        assert_eq!(File::new("out/image.jpg").ensure_extension("png"), File::new("out/image.jpg.png"));
+        assert_eq!(File::new("backup.tar").ensure_extension("gz"), File::new("backup.tar.gz"));
+        assert_eq!(File::new("backup.tar.gz").ensure_extension("gz"), File::new("backup.tar.gz"));
+        assert_eq!(File::new("backup.tar.gz").ensure_extension("tar.gz"), File::new("backup.tar.gz"));
+        assert_eq!(File::new("backup.TAR.GZ").ensure_extension(".tar.gz"), File::new("backup.TAR.GZ"));
+        assert_eq!(File::new("backup.gz").ensure_extension("tar.gz"), File::new("backup.gz.tar.gz"));
+        // 이름 전체가 확장자와 같은 숨김 파일에는 확장자를 덧붙여야 합니다.
+        assert_eq!(File::new(".gz").ensure_extension("gz"), File::new(".gz.gz"));
+    }
+
+    #[test]
+    fn test_all_dirs() {
+        let test_dir = setup_test_env("test_all_dirs");
+        fs::create_dir_all(test_dir.join("a/b/c")).unwrap();
+        fs::create_dir_all(test_dir.join("d")).unwrap();
+        fs::write(test_dir.join("a/file.txt"), b"file").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(test_dir.join("a"), test_dir.join("d/link")).unwrap();
+
+        let dirs = File::new(&test_dir).all_dirs().unwrap();
+        assert_eq!(
+            dirs,
+            vec![
+                File::new(test_dir.join("a")),
+                File::new(test_dir.join("a/b")),
+                File::new(test_dir.join("a/b/c")),
+                File::new(test_dir.join("d")),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_set_hash() {
+        let test_dir = setup_test_env("test_set_hash");
+        fs::write(test_dir.join("a.txt"), b"alpha").unwrap();
+        fs::write(test_dir.join("b.txt"), b"beta").unwrap();
+        fs::write(test_dir.join("c.txt"), b"alpha").unwrap();
+
+        let a = File::new(test_dir.join("a.txt"));
+        let b = File::new(test_dir.join("b.txt"));
+        let c = File::new(test_dir.join("c.txt"));
+
+        let forward = set_hash(&[a.clone(), b.clone()], HashAlgorithm::Sha256).unwrap();
+        let reversed = set_hash(&[b.clone(), a.clone()], HashAlgorithm::Sha256).unwrap();
+        assert_eq!(forward, reversed);
+
+        // 이름이 달라도 내용이 같으면 같은 결과여야 합니다.
+        assert_eq!(set_hash(&[b.clone(), c], HashAlgorithm::Sha256).unwrap(), forward);
+        assert_ne!(set_hash(&[a.clone(), a, b], HashAlgorithm::Sha256).unwrap(), forward);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_special_file_checks() {
+        use std::os::unix::ffi::OsStrExt;
+
+        let test_dir = setup_test_env("test_special_file_checks");
+        let fifo_path = test_dir.join("pipe");
+        let c_path = std::ffi::CString::new(fifo_path.as_os_str().as_bytes()).unwrap();
+        assert_eq!(unsafe { libc::mkfifo(c_path.as_ptr(), 0o644) }, 0);
+
+        let fifo = File::new(&fifo_path);
+        assert!(fifo.is_fifo());
+        assert!(!fifo.is_file());
+        assert!(!fifo.is_socket());
+        assert!(!fifo.is_block_device());
+
+        let listener = std::os::unix::net::UnixListener::bind(test_dir.join("sock")).unwrap();
+        assert!(File::new(test_dir.join("sock")).is_socket());
+        drop(listener);
+
+        assert!(File::new("/dev/null").is_char_device());
+        assert!(!File::new("/dev/null").is_fifo());
+    }
+
+    #[test]
+    fn test_head_lines() {
+        let test_dir = setup_test_env("test_head_lines");
+        let path = test_dir.join("long.txt");
+        let content: String = (0..10_000).map(|i| format!("line {}\r\n", i)).collect();
+        fs::write(&path, &content).unwrap();
+
+        let file = File::new(&path);
+        assert_eq!(file.head_lines(3).unwrap(), vec!["line 0", "line 1", "line 2"]);
+        assert!(file.head_lines(0).unwrap().is_empty());
+        assert_eq!(file.head_lines(20_000).unwrap().len(), 10_000);
+
+        // 읽은 바이트 수를 세어 k줄 이후를 읽지 않았는지 확인합니다.
+        struct CountingReader<R> {
+            inner: R,
+            read: usize,
+        }
+        impl<R: Read> Read for CountingReader<R> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let n = self.inner.read(buf)?;
+                self.read += n;
+                Ok(n)
+            }
+        }
+
+        let mut counting = CountingReader { inner: Cursor::new(content.as_bytes()), read: 0 };
+        let lines = head_lines_from(&mut BufReader::with_capacity(64, &mut counting), 5).unwrap();
+        assert_eq!(lines.len(), 5);
+        assert!(counting.read <= 128, "read {} bytes", counting.read);
+    }
+
+    #[test]
+    fn test_replace() {
+        let test_dir = setup_test_env("test_replace");
+        let path = test_dir.join("config.ini");
+        fs::write(&path, "host=old\nbackup=old\nport=80\n").unwrap();
+
+        let file = File::new(&path);
+        assert_eq!(file.replace("old", "new.example").unwrap(), 2);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "host=new.example\nbackup=new.example\nport=80\n");
+        assert_eq!(file.replace("", "x").unwrap_err().kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_replace_no_match_keeps_file() {
+        let test_dir = setup_test_env("test_replace_no_match_keeps_file");
+        let path = test_dir.join("config.ini");
+        fs::write(&path, "port=80\n").unwrap();
+        let past = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        set_mtime(&path, past);
+
+        // 일치하는 부분이 없으면 다시 쓰지 않아 수정 시각이 바뀌지 않아야 합니다.
+        assert_eq!(File::new(&path).replace("missing", "x").unwrap(), 0);
+        assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), past);
+        assert_eq!(fs::read_to_string(&path).unwrap(), "port=80\n");
+    }
+
+    #[test]
+    fn test_verify_sidecar() {
+        let test_dir = setup_test_env("test_verify_sidecar");
+        let path = test_dir.join("foo.iso");
+        fs::write(&path, b"image data").unwrap();
+        let file = File::new(&path);
+        let hash = file.hash_with(HashAlgorithm::Sha256).unwrap();
+
+        // sha256sum 출력 형식과 같아야 합니다.
+        fs::write(test_dir.join("foo.iso.sha256"), format!("{}  foo.iso\n", hash.to_uppercase())).unwrap();
+        assert!(file.verify_sidecar(HashAlgorithm::Sha256).unwrap());
+
+        fs::write(test_dir.join("foo.iso.sha256"), format!("{}\n", "0".repeat(64))).unwrap();
+        assert!(!file.verify_sidecar(HashAlgorithm::Sha256).unwrap());
+
+        let err = file.verify_sidecar(HashAlgorithm::Sha512).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_walk_callback_prunes() {
+        let test_dir = setup_test_env("test_walk_callback_prunes");
+        fs::create_dir_all(test_dir.join("keep/inner")).unwrap();
+        fs::create_dir_all(test_dir.join("skip/inner")).unwrap();
+        fs::write(test_dir.join("keep/inner/a.txt"), b"a").unwrap();
+        fs::write(test_dir.join("skip/inner/b.txt"), b"b").unwrap();
+
+        let mut visited = Vec::new();
+        File::new(&test_dir)
+            .walk_callback(|file| {
+                let relative = file.path().strip_prefix(&test_dir).unwrap().to_path_buf();
+                visited.push(relative);
+                Ok(file.path().file_name().unwrap() != "skip")
+            })
+            .unwrap();
+
+        // skip 디렉터리 자체는 방문하지만 그 하위 항목은 방문하지 않아야 합니다.
+        let expected: Vec<PathBuf> = ["keep", "keep/inner", "keep/inner/a.txt", "skip"]
+            .iter()
+            .map(PathBuf::from)
+            .collect();
+        assert_eq!(visited, expected);
+    }
+
+    #[test]
+    fn test_walk_callback_error_aborts() {
+        let test_dir = setup_test_env("test_walk_callback_error_aborts");
+        for name in ["a.txt", "b.txt", "c.txt"] {
+            fs::write(test_dir.join(name), b"x").unwrap();
+        }
+
+        let mut count = 0;
+        let err = File::new(&test_dir)
+            .walk_callback(|_| {
+                count += 1;
+                if count == 2 {
+                    return Err(Error::other("중단"));
+                }
+                Ok(true)
+            })
+            .unwrap_err();
+
+        assert_eq!(err.kind(), ErrorKind::Other);
+        assert_eq!(count, 2);
+    }
+
+    #[test]
+    fn test_keep_last_lines() {
+        let test_dir = setup_test_env("test_keep_last_lines");
+        let path = test_dir.join("app.log");
+        let content: String = (1..=100).map(|i| format!("line {}\n", i)).collect();
+        fs::write(&path, content).unwrap();
+
+        // 버퍼를 작게 하여 여러 번 거꾸로 읽는 경우를 검증합니다.
+        let file = File::new(&path).with_buffer_size(16);
+        file.keep_last_lines(10).unwrap();
+        let expected: Vec<String> = (91..=100).map(|i| format!("line {}", i)).collect();
+        assert_eq!(file.read_lines().unwrap(), expected);
+
+        // 줄 수보다 큰 값이면 그대로 유지해야 합니다.
+        file.keep_last_lines(50).unwrap();
+        assert_eq!(file.read_lines().unwrap(), expected);
+
+        file.keep_last_lines(0).unwrap();
+        assert!(file.is_empty().unwrap());
+    }
+
+    #[test]
+    fn test_clone_to() {
+        let test_dir = setup_test_env("test_clone_to");
+        let source = File::new(test_dir.join("source.bin"));
+        fs::write(source.path(), vec![7u8; 100_000]).unwrap();
+
+        // 복제 지원 여부와 관계없이 결과는 원본과 같아야 합니다.
+        let cloned = source.clone_to(test_dir.join("cloned.bin")).unwrap();
+        assert!(source.is_deep_match(&cloned));
+
+        // 기존 대상 파일은 덮어써야 합니다.
+        fs::write(test_dir.join("existing.bin"), b"old").unwrap();
+        let overwritten = source.clone_to(test_dir.join("existing.bin")).unwrap();
+        assert!(source.is_deep_match(&overwritten));
+
+        // 원본 자신으로는 복제하지 않고, 원본도 그대로 남아야 합니다.
+        assert_eq!(source.clone_to(source.path()).unwrap_err().kind(), ErrorKind::InvalidInput);
+        assert_eq!(fs::read(source.path()).unwrap(), vec![7u8; 100_000]);
+        assert_eq!(fs::read_dir(&test_dir).unwrap().count(), 3);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_metadata_nofollow() {
+        let test_dir = setup_test_env("test_metadata_nofollow");
+        fs::write(test_dir.join("target.txt"), b"data").unwrap();
+        std::os::unix::fs::symlink(test_dir.join("target.txt"), test_dir.join("link")).unwrap();
+
+        let link = File::new(test_dir.join("link"));
+        assert!(link.metadata().unwrap().file_type().is_file());
+        assert!(link.metadata_nofollow().unwrap().file_type().is_symlink());
+    }
+
+    #[test]
+    fn test_count_occurrences() {
+        let test_dir = setup_test_env("test_count_occurrences");
+        let path = test_dir.join("data.txt");
+        fs::write(&path, b"a\nb\nc\n\n").unwrap();
+        assert_eq!(File::new(&path).count_occurrences(b"\n").unwrap(), 4);
+
+        // 겹치는 일치는 세지 않아야 합니다.
+        fs::write(&path, b"aaaa").unwrap();
+        assert_eq!(File::new(&path).count_occurrences(b"aa").unwrap(), 2);
+        assert_eq!(File::new(&path).count_occurrences(b"").unwrap_err().kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_count_occurrences_across_boundary() {
+        let test_dir = setup_test_env("test_count_occurrences_across_boundary");
+        let path = test_dir.join("data.bin");
+        // 버퍼 크기 8에서 "needle"이 6..12 위치에 있어 경계를 가로지릅니다.
+        fs::write(&path, b"xxxxxxneedlexxxxneedle").unwrap();
+
+        let file = File::new(&path).with_buffer_size(8);
+        assert_eq!(file.count_occurrences(b"needle").unwrap(), 2);
+    }
+
+    #[test]
+    fn test_rename_children() {
+        let test_dir = setup_test_env("test_rename_children");
+        for name in ["Photo.JPG", "README.md", "notes.txt"] {
+            fs::write(test_dir.join(name), name).unwrap();
+        }
+
+        let renamed = File::new(&test_dir).rename_children(|name| Some(name.to_lowercase())).unwrap();
+        let pairs: Vec<(String, String)> = renamed
+            .iter()
+            .map(|(old, new)| {
+                let name = |f: &File| f.path().file_name().unwrap().to_string_lossy().into_owned();
+                (name(old), name(new))
+            })
+            .collect();
+
+        // 이름이 그대로인 notes.txt는 결과에 포함되지 않아야 합니다.
+        assert_eq!(
+            pairs,
+            vec![
+                ("Photo.JPG".to_string(), "photo.jpg".to_string()),
+                ("README.md".to_string(), "readme.md".to_string()),
+            ]
+        );
+        assert_eq!(fs::read_to_string(test_dir.join("photo.jpg")).unwrap(), "Photo.JPG");
+    }
+
+    #[test]
+    fn test_rename_children_collision() {
+        let test_dir = setup_test_env("test_rename_children_collision");
+        for name in ["a.TXT", "a.txt", "b.TXT"] {
+            fs::write(test_dir.join(name), name).unwrap();
+        }
+
+        // a.TXT -> a.txt가 기존 항목과 충돌하므로 b.TXT도 바뀌지 않아야 합니다.
+        let err = File::new(&test_dir).rename_children(|name| Some(name.to_lowercase())).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::AlreadyExists);
+        assert!(test_dir.join("b.TXT").exists());
+        assert_eq!(fs::read_to_string(test_dir.join("a.txt")).unwrap(), "a.txt");
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_readahead() {
+        let test_dir = setup_test_env("test_readahead");
+        let path = test_dir.join("data.bin");
+        fs::write(&path, vec![0u8; 4096]).unwrap();
+
+        let file = File::new(&path);
+        file.readahead(0, 0).unwrap();
+        file.readahead(1024, 1024).unwrap();
+
+        let err = File::new(&test_dir).readahead(0, 0).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::IsADirectory);
+    }
+
+    #[test]
+    fn test_read_utf16() {
+        let test_dir = setup_test_env("test_read_utf16");
+        let text = "안녕 utf-16 🎉";
+        let le: Vec<u8> = text.encode_utf16().flat_map(u16::to_le_bytes).collect();
+        let be: Vec<u8> = text.encode_utf16().flat_map(u16::to_be_bytes).collect();
+
+        let path = test_dir.join("text.txt");
+        fs::write(&path, &le).unwrap();
+        assert_eq!(File::new(&path).read_utf16le_to_string().unwrap(), text);
+
+        // BOM이 있으면 제거되어야 합니다.
+        fs::write(&path, [&[0xFF, 0xFE][..], &le].concat()).unwrap();
+        assert_eq!(File::new(&path).read_utf16le_to_string().unwrap(), text);
+
+        fs::write(&path, [&[0xFE, 0xFF][..], &be].concat()).unwrap();
+        assert_eq!(File::new(&path).read_utf16be_to_string().unwrap(), text);
+
+        fs::write(&path, [0x41, 0x00, 0x42]).unwrap();
+        let err = File::new(&path).read_utf16le_to_string().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_temp_dir() {
+        let dir = File::temp_dir().unwrap();
+        fs::write(dir.path().join("scratch.txt"), b"data").unwrap();
+
+        assert!(dir.exists_as_dir());
+        assert!(dir.path().join("scratch.txt").exists());
+        let other = File::temp_dir().unwrap();
+        assert_ne!(dir, other);
+        fs::remove_dir_all(dir.path()).unwrap();
+        fs::remove_dir_all(other.path()).unwrap();
+    }
+
+    #[test]
+    fn test_temp_dir_guard_removes_on_drop() {
+        let guard = TempDir::new().unwrap();
+        let path = guard.path().to_path_buf();
+        fs::create_dir(path.join("nested")).unwrap();
+        fs::write(path.join("nested/file.txt"), b"data").unwrap();
+        assert!(guard.file().exists_as_dir());
+
+        // 가드가 해제되면 내용까지 모두 삭제되어야 합니다.
+        drop(guard);
+        assert!(!path.exists());
+    }
+
+    #[test]
+    fn test_mime_from_extension() {
+        assert_eq!(mime_from_extension("json"), Some("application/json"));
+        // 대소문자를 구분하지 않아야 합니다.
+        assert_eq!(mime_from_extension("HTML"), Some("text/html"));
+        assert_eq!(mime_from_extension(".png"), Some("image/png"));
+        assert_eq!(mime_from_extension("unknownext"), None);
+        assert_eq!(mime_from_extension(""), None);
+    }
+
+    #[test]
+    fn test_fast_match() {
+        let test_dir = setup_test_env("test_fast_match");
+        let a = File::new(test_dir.join("a.bin"));
+        let b = File::new(test_dir.join("b.bin"));
+        let c = File::new(test_dir.join("c.bin"));
+        fs::write(a.path(), b"same content").unwrap();
+        fs::write(b.path(), b"same content").unwrap();
+        fs::write(c.path(), b"Same content").unwrap();
+
+        assert!(a.fast_match(&b).unwrap());
+        assert!(!a.fast_match(&c).unwrap());
+
+        // 버퍼 크기와 무관하게 같은 값이어야 합니다.
+        let small = File::new(a.path()).with_buffer_size(3);
+        assert_eq!(a.fast_fingerprint().unwrap(), small.fast_fingerprint().unwrap());
+
+        // 한 바이트씩 다른 여러 입력의 지문이 모두 달라야 합니다.
+        let mut fingerprints = std::collections::HashSet::new();
+        for i in 0..=255u8 {
+            fs::write(c.path(), [b'x', i, b'y']).unwrap();
+            assert!(fingerprints.insert(c.fast_fingerprint().unwrap()));
+        }
+    }
+
+    #[test]
+    fn test_entries_with_type() {
+        let test_dir = setup_test_env("test_entries_with_type");
+        fs::write(test_dir.join("a.txt"), b"a").unwrap();
+        fs::create_dir(test_dir.join("sub")).unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(test_dir.join("sub"), test_dir.join("z_link")).unwrap();
+
+        let entries = File::new(&test_dir).entries_with_type().unwrap();
+        let kinds: Vec<FileKind> = entries.iter().map(|(_, kind)| *kind).collect();
+        assert_eq!(entries[0].0, File::new(test_dir.join("a.txt")));
+
+        #[cfg(unix)]
+        assert_eq!(kinds, vec![FileKind::File, FileKind::Dir, FileKind::Symlink]);
+        #[cfg(not(unix))]
+        assert_eq!(kinds, vec![FileKind::File, FileKind::Dir]);
+    }
+
+    #[test]
+    fn test_copy_tree_plan() {
+        let test_dir = setup_test_env("test_copy_tree_plan");
+        let source = test_dir.join("source");
+        let dest = test_dir.join("dest");
+        fs::create_dir_all(source.join("sub")).unwrap();
+        fs::write(source.join("a.txt"), b"a").unwrap();
+        fs::write(source.join("sub/b.txt"), b"b").unwrap();
+
+        // 계획만 세우면 대상 경로가 만들어지지 않아야 합니다.
+        let source = File::new(&source);
+        let plan = source.copy_tree_plan(&dest).unwrap();
+        let copies = plan.iter().filter(|action| matches!(action, CopyAction::Copy { .. })).count();
+        let dirs = plan.iter().filter(|action| matches!(action, CopyAction::CreateDir(_))).count();
+        assert_eq!((copies, dirs, plan.len()), (2, 2, 4));
+        assert!(!dest.exists());
+
+        assert_eq!(source.copy_tree_to(&dest).unwrap(), plan);
+        assert_eq!(fs::read(dest.join("sub/b.txt")).unwrap(), b"b");
+
+        // 복사 후 다시 계획하면 모두 건너뛰어야 합니다.
+        let replan = source.copy_tree_plan(&dest).unwrap();
+        assert_eq!(replan.len(), 2);
+        assert!(replan.iter().all(|action| matches!(action, CopyAction::Skip { .. })));
+
+        // 내용이 바뀐 파일만 덮어써야 합니다.
+        fs::write(source.path().join("a.txt"), b"changed").unwrap();
+        let replan = source.copy_tree_plan(&dest).unwrap();
+        assert!(matches!(&replan[0], CopyAction::Overwrite { to, .. } if to.path().ends_with("a.txt")));
+        assert!(matches!(&replan[1], CopyAction::Skip { .. }));
+    }
+
+    #[test]
+    fn test_read_bytes_if_fresh() {
+        let test_dir = setup_test_env("test_read_bytes_if_fresh");
+        let path = test_dir.join("cache.bin");
+        fs::write(&path, b"cached").unwrap();
+
+        let file = File::new(&path);
+        let max_age = Duration::from_secs(60);
+        assert_eq!(file.read_bytes_if_fresh(max_age).unwrap(), Some(b"cached".to_vec()));
+
+        // 수정 시간을 과거로 돌리면 오래된 것으로 판단해야 합니다.
+        set_mtime(&path, SystemTime::now() - Duration::from_secs(3600));
+        assert_eq!(file.read_bytes_if_fresh(max_age).unwrap(), None);
+
+        let err = File::new(test_dir.join("missing")).read_bytes_if_fresh(max_age).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_line_ending() {
+        let test_dir = setup_test_env("test_line_ending");
+        let path = test_dir.join("text.txt");
+        // 버퍼 크기를 2로 하여 `\r\n`이 경계에 걸치는 경우도 검증합니다.
+        let file = File::new(&path).with_buffer_size(2);
+
+        fs::write(&path, "a\nb\nc\n").unwrap();
+        assert_eq!(file.line_ending().unwrap(), LineEnding::Lf);
+
+        fs::write(&path, "a\r\nbc\r\n").unwrap();
+        assert_eq!(file.line_ending().unwrap(), LineEnding::CrLf);
+
+        fs::write(&path, "a\r\nb\nc").unwrap();
+        assert_eq!(file.line_ending().unwrap(), LineEnding::Mixed);
+
+        fs::write(&path, "no newline\r").unwrap();
+        assert_eq!(file.line_ending().unwrap(), LineEnding::None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_symlink_to_atomic() {
+        let test_dir = setup_test_env("test_symlink_to_atomic");
+        fs::create_dir(test_dir.join("release-1")).unwrap();
+        fs::create_dir(test_dir.join("release-2")).unwrap();
+
+        let current = File::new(test_dir.join("current"));
+        current.symlink_to_atomic(test_dir.join("release-1")).unwrap();
+        assert_eq!(fs::read_link(current.path()).unwrap(), test_dir.join("release-1"));
+
+        // 다른 스레드가 계속 교체하는 동안 링크가 한 번도 사라지지 않아야 합니다.
+        let stop = std::sync::Arc::new(std::sync::atomic::AtomicBool::new(false));
+        let swapper = {
+            let (current, test_dir, stop) = (current.clone(), test_dir.clone(), stop.clone());
+            std::thread::spawn(move || {
+                for i in 0..500 {
+                    current.symlink_to_atomic(test_dir.join(format!("release-{}", i % 2 + 1))).unwrap();
+                }
+                stop.store(true, Ordering::SeqCst);
+            })
+        };
+        while !stop.load(Ordering::SeqCst) {
+            assert!(fs::symlink_metadata(current.path()).unwrap().file_type().is_symlink());
+        }
+        swapper.join().unwrap();
+
+        assert_eq!(fs::read_link(current.path()).unwrap(), test_dir.join("release-2"));
+        assert_eq!(fs::read_dir(&test_dir).unwrap().count(), 3);
+    }
+
+    #[test]
+    fn test_rm_file_and_rm_dir() {
+        let test_dir = setup_test_env("test_rm_file_and_rm_dir");
+        let file = File::new(test_dir.join("a.txt"));
+        let dir = File::new(test_dir.join("sub"));
+        fs::write(file.path(), b"a").unwrap();
+        fs::create_dir(dir.path()).unwrap();
+        fs::write(dir.path().join("inner.txt"), b"inner").unwrap();
+
+        // 종류가 맞지 않으면 삭제하지 않아야 합니다.
+        assert_eq!(dir.rm_file().unwrap_err().kind(), ErrorKind::IsADirectory);
+        assert_eq!(file.rm_dir().unwrap_err().kind(), ErrorKind::NotADirectory);
+        assert!(file.exists() && dir.exists());
+
+        file.rm_file().unwrap();
+        dir.rm_dir().unwrap();
+        assert!(!file.exists() && !dir.exists());
+        assert_eq!(file.rm_file().unwrap_err().kind(), ErrorKind::NotFound);
+    }
+
+    #[test]
+    fn test_sibling_temp() {
+        let test_dir = setup_test_env("test_sibling_temp");
+        let file = File::new(test_dir.join("report.csv"));
+
+        let first = file.sibling_temp();
+        let second = file.sibling_temp();
+        assert_eq!(first.path().parent(), Some(test_dir.as_path()));
+        assert_eq!(first.path().extension().unwrap(), "csv");
+        assert_ne!(first, second);
+        assert_ne!(first, file);
+        // 디스크에는 아무것도 만들지 않아야 합니다.
+        assert!(!first.exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_copy_tree_to_with_symlink_policy() {
+        let test_dir = setup_test_env("test_copy_tree_to_with_symlink_policy");
+        let source = test_dir.join("source");
+        fs::create_dir_all(source.join("dir")).unwrap();
+        fs::write(source.join("dir/inner.txt"), b"inner").unwrap();
+        fs::write(source.join("file.txt"), b"content").unwrap();
+        std::os::unix::fs::symlink("file.txt", source.join("file_link")).unwrap();
+        std::os::unix::fs::symlink("dir", source.join("dir_link")).unwrap();
+        let source = File::new(&source);
+
+        // Follow: 링크 대상의 내용이 일반 파일과 디렉터리로 복사되어야 합니다.
+        let follow = test_dir.join("follow");
+        source.copy_tree_to_with(&follow, SymlinkPolicy::Follow).unwrap();
+        assert!(!fs::symlink_metadata(follow.join("file_link")).unwrap().file_type().is_symlink());
+        assert_eq!(fs::read(follow.join("file_link")).unwrap(), b"content");
+        assert_eq!(fs::read(follow.join("dir_link/inner.txt")).unwrap(), b"inner");
+
+        // Copy: 같은 대상을 가리키는 링크가 다시 만들어지고, 다시 실행하면 건너뛰어야 합니다.
+        let copy = test_dir.join("copy");
+        source.copy_tree_to_with(&copy, SymlinkPolicy::Copy).unwrap();
+        assert_eq!(fs::read_link(copy.join("file_link")).unwrap(), PathBuf::from("file.txt"));
+        assert_eq!(fs::read_link(copy.join("dir_link")).unwrap(), PathBuf::from("dir"));
+        let replan = source.copy_tree_plan_with(&copy, SymlinkPolicy::Copy).unwrap();
+        assert!(replan.iter().all(|action| matches!(action, CopyAction::Skip { .. })));
+
+        // Skip: 링크가 복사되지 않아야 합니다.
+        let skip = test_dir.join("skip");
+        source.copy_tree_to_with(&skip, SymlinkPolicy::Skip).unwrap();
+        assert!(fs::symlink_metadata(skip.join("file_link")).is_err());
+        assert!(fs::symlink_metadata(skip.join("dir_link")).is_err());
+        assert!(skip.join("dir/inner.txt").exists());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_copy_tree_follow_detects_cycle() {
+        let test_dir = setup_test_env("test_copy_tree_follow_detects_cycle");
+        let source = test_dir.join("source");
+        fs::create_dir_all(source.join("sub")).unwrap();
+        std::os::unix::fs::symlink("..", source.join("sub/parent")).unwrap();
+
+        let err = File::new(&source).copy_tree_plan_with(test_dir.join("dest"), SymlinkPolicy::Follow).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+
+        // 링크를 따라가지 않으면 순환과 관계없이 복사되어야 합니다.
+        File::new(&source).copy_tree_to_with(test_dir.join("dest"), SymlinkPolicy::Copy).unwrap();
+        assert_eq!(fs::read_link(test_dir.join("dest/sub/parent")).unwrap(), PathBuf::from(".."));
+    }
+
+    #[test]
+    fn test_disk_usage() {
+        let test_dir = setup_test_env("test_disk_usage");
+        fs::write(test_dir.join("a.txt"), b"a").unwrap();
+
+        let usage = File::new(test_dir.join("a.txt")).disk_usage().unwrap();
+        assert!(usage.total > 0 && usage.free > 0 && usage.available > 0);
+        assert!(usage.available <= usage.free && usage.free <= usage.total);
+
+        assert!(File::new(test_dir.join("missing")).disk_usage().is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let test_dir = setup_test_env("test_compare");
+        let specs = [("b.txt", 30, 200), ("c.txt", 10, 300), ("a.txt", 20, 100)];
+        let mut files = Vec::new();
+        for (name, size, secs) in specs {
+            let path = test_dir.join(name);
+            fs::write(&path, vec![0u8; size]).unwrap();
+            set_mtime(&path, SystemTime::UNIX_EPOCH + Duration::from_secs(secs));
+            files.push(File::new(path));
+        }
+
+        let sorted_names = |key: SortKey| {
+            let mut sorted = files.clone();
+            sorted.sort_by(|a, b| a.compare(b, key).unwrap());
+            sorted
+                .iter()
+                .map(|file| file.path().file_name().unwrap().to_string_lossy().into_owned())
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(sorted_names(SortKey::Name), ["a.txt", "b.txt", "c.txt"]);
+        assert_eq!(sorted_names(SortKey::Size), ["c.txt", "a.txt", "b.txt"]);
+        assert_eq!(sorted_names(SortKey::Modified), ["a.txt", "b.txt", "c.txt"]);
+    }
+
+    #[test]
+    fn test_write_from() {
+        let test_dir = setup_test_env("test_write_from");
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+
+        // 없는 상위 디렉터리도 만들어져야 합니다.
+        let file = File::new(test_dir.join("nested/dir/out.bin")).with_buffer_size(1024);
+        let written = file.write_from(Cursor::new(&data)).unwrap();
+
+        assert_eq!(written, data.len() as u64);
+        assert_eq!(fs::read(file.path()).unwrap(), data);
+
+        // 기존 내용은 비워져야 합니다.
+        assert_eq!(file.write_from(&b"short"[..]).unwrap(), 5);
+        assert_eq!(fs::read(file.path()).unwrap(), b"short");
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_dedup_with_hardlinks() {
+        use std::os::unix::fs::MetadataExt;
+
+        let test_dir = setup_test_env("test_dedup_with_hardlinks");
+        fs::create_dir(test_dir.join("sub")).unwrap();
+        fs::write(test_dir.join("a.bin"), b"duplicate content").unwrap();
+        fs::write(test_dir.join("sub/b.bin"), b"duplicate content").unwrap();
+        fs::write(test_dir.join("c.bin"), b"different content").unwrap();
+
+        // 읽기 전용 파일도 링크로 바뀌어야 합니다.
+        let mut permissions = fs::metadata(test_dir.join("sub/b.bin")).unwrap().permissions();
+        permissions.set_readonly(true);
+        fs::set_permissions(test_dir.join("sub/b.bin"), permissions).unwrap();
+
+        let root = File::new(&test_dir);
+        assert_eq!(root.dedup_with_hardlinks().unwrap(), 1);
+
+        let inode = |name: &str| fs::metadata(test_dir.join(name)).unwrap().ino();
+        assert_eq!(inode("a.bin"), inode("sub/b.bin"));
+        assert_ne!(inode("a.bin"), inode("c.bin"));
+        assert_eq!(fs::read(test_dir.join("sub/b.bin")).unwrap(), b"duplicate content");
+
+        // 이미 링크된 파일은 다시 세지 않아야 합니다.
+        assert_eq!(root.dedup_with_hardlinks().unwrap(), 0);
+    }
+
+    #[test]
+    fn test_read_to_string_limited() {
+        let test_dir = setup_test_env("test_read_to_string_limited");
+        let path = test_dir.join("text.txt");
+        fs::write(&path, "가나다 abc").unwrap();
+
+        let file = File::new(&path);
+        assert_eq!(file.read_to_string_limited(64).unwrap(), "가나다 abc");
+        assert_eq!(file.read_to_string_limited(4).unwrap_err().kind(), ErrorKind::FileTooLarge);
+
+        fs::write(&path, [0xFF, 0xFE]).unwrap();
+        assert_eq!(file.read_to_string_limited(64).unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_set_executable() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let test_dir = setup_test_env("test_set_executable");
+        let path = test_dir.join("run.sh");
+        fs::write(&path, "#!/bin/sh\n").unwrap();
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+
+        let file = File::new(&path);
+        assert!(!file.is_executable().unwrap());
+
+        // 읽기 권한이 있는 소유자와 그룹에만 실행 권한이 추가되어야 합니다.
+        file.set_executable(true).unwrap();
+        assert!(file.is_executable().unwrap());
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o750);
+
+        file.set_executable(false).unwrap();
+        assert!(!file.is_executable().unwrap());
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o640);
+    }
+
+    #[test]
+    fn test_is_match_tiered() {
+        let test_dir = setup_test_env("test_is_match_tiered");
+        let a = File::new(test_dir.join("a.bin"));
+        let b = File::new(test_dir.join("b.bin"));
+        let c = File::new(test_dir.join("c.bin"));
+        fs::write(a.path(), b"same size 1").unwrap();
+        fs::write(b.path(), b"same size 1").unwrap();
+        // 크기는 같고 내용만 다릅니다.
+        fs::write(c.path(), b"same size 2").unwrap();
+
+        assert!(a.is_match_tiered(&b).unwrap());
+        assert!(!a.is_match_tiered(&c).unwrap());
+        assert!(File::new(test_dir.join("missing")).is_match_tiered(&a).is_err());
+    }
+
+    #[test]
+    fn test_sync_to() {
+        let test_dir = setup_test_env("test_sync_to");
+        let source = test_dir.join("source");
+        let dest = test_dir.join("dest");
+        fs::create_dir_all(source.join("sub")).unwrap();
+        fs::create_dir_all(&dest).unwrap();
+        fs::write(source.join("same.txt"), b"same").unwrap();
+        fs::write(source.join("changed.txt"), b"new").unwrap();
+        fs::write(source.join("sub/missing.txt"), b"missing").unwrap();
+
+        // 대상에 일부 파일이 이미 있는 상태에서 시작합니다.
+        fs::write(dest.join("same.txt"), b"same").unwrap();
+        fs::write(dest.join("changed.txt"), b"old").unwrap();
+        fs::write(dest.join("extra.txt"), b"extra").unwrap();
+
+        let source = File::new(&source);
+        let report = source.sync_to(&dest).unwrap();
+        assert_eq!(report, SyncReport { copied: 2, skipped: 1, deleted: 0 });
+        assert_eq!(fs::read(dest.join("changed.txt")).unwrap(), b"new");
+        assert_eq!(fs::read(dest.join("sub/missing.txt")).unwrap(), b"missing");
+        assert!(dest.join("extra.txt").exists());
+
+        let report = source.sync_to_with(&dest, true).unwrap();
+        assert_eq!(report, SyncReport { copied: 0, skipped: 3, deleted: 1 });
+        assert!(!dest.join("extra.txt").exists());
+    }
+
+    #[test]
+    fn test_entropy() {
+        let test_dir = setup_test_env("test_entropy");
+        let path = test_dir.join("data.bin");
+
+        fs::write(&path, vec![0u8; 4096]).unwrap();
+        assert!(File::new(&path).entropy().unwrap().abs() < 1e-9);
+
+        // xorshift로 만든 의사 난수 데이터는 8에 가까워야 합니다.
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        let random: Vec<u8> = (0..1 << 20)
+            .map(|_| {
+                state ^= state << 13;
+                state ^= state >> 7;
+                state ^= state << 17;
+                (state >> 56) as u8
+            })
+            .collect();
+        fs::write(&path, random).unwrap();
+        let entropy = File::new(&path).entropy().unwrap();
+        assert!(entropy > 7.99 && entropy <= 8.0, "entropy = {}", entropy);
+
+        fs::write(&path, b"").unwrap();
+        assert_eq!(File::new(&path).entropy().unwrap(), 0.0);
+    }
+
+    #[test]
+    fn test_create_with_times() {
+        let test_dir = setup_test_env("test_create_with_times");
+        let path = test_dir.join("fixture.txt");
+        let modified = SystemTime::UNIX_EPOCH + Duration::from_secs(1_234_567_890);
+
+        File::new(&path).create_with_times("fixture", modified).unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "fixture");
+        assert_eq!(fs::metadata(&path).unwrap().modified().unwrap(), modified);
+    }
+
+    #[test]
+    fn test_ancestors() {
+        let root = std::env::temp_dir().ancestors().last().unwrap().to_path_buf();
+        let file = File::new(root.join("a").join("b").join("c"));
+
+        let ancestors: Vec<File> = file.ancestors().collect();
+        let expected = vec![
+            File::new(root.join("a").join("b").join("c")),
+            File::new(root.join("a").join("b")),
+            File::new(root.join("a")),
+            File::new(&root),
+        ];
+        assert_eq!(ancestors, expected);
+
+        // 상대 경로는 빈 경로를 내지 않아야 합니다.
+        let relative: Vec<File> = File::new("a/b").ancestors().collect();
+        assert_eq!(relative, vec![File::new("a/b"), File::new("a")]);
+    }
+
+    #[test]
+    fn test_find_upward() {
+        let test_dir = setup_test_env("test_find_upward");
+        fs::create_dir_all(test_dir.join("project/src/nested")).unwrap();
+        fs::write(test_dir.join("project/marker.toml"), b"").unwrap();
+        fs::write(test_dir.join("project/src/nested/main.rs"), b"").unwrap();
+
+        // 두 단계 위의 표시 파일을 찾아야 합니다.
+        let project = File::new(test_dir.join("project"));
+        let from_dir = File::new(test_dir.join("project/src/nested"));
+        assert_eq!(from_dir.find_upward("marker.toml"), Some(project.clone()));
+
+        let from_file = File::new(test_dir.join("project/src/nested/main.rs"));
+        assert_eq!(from_file.find_upward("marker.toml"), Some(project));
+        assert_eq!(from_file.find_upward("main.rs"), Some(from_dir));
+
+        assert_eq!(from_file.find_upward("no-such-marker-ever.xyz"), None);
+    }
+
+    #[test]
+    fn test_find_upward_relative() {
+        // 테스트는 크레이트 루트에서 실행되므로 상대 경로도 현재 디렉터리까지 올라가 찾아야 합니다.
+        let crate_root = File::new(std::env::current_dir().unwrap());
+        assert_eq!(File::new("src/file.rs").find_upward("Cargo.toml"), Some(crate_root.clone()));
+        assert_eq!(File::new("Cargo.toml").find_upward("Cargo.toml"), Some(crate_root.clone()));
+        assert_eq!(File::new("src").find_upward("Cargo.toml"), Some(crate_root));
+    }
+
+    #[test]
+    fn test_copy_to_verified() {
+        let test_dir = setup_test_env("test_copy_to_verified");
+        let source = File::new(test_dir.join("source.bin"));
+        fs::write(source.path(), vec![42u8; 200_000]).unwrap();
+
+        let copied = source.copy_to_verified(test_dir.join("copy.bin")).unwrap();
+        assert!(source.is_deep_match(&copied));
+        assert!(File::new(test_dir.join("missing")).copy_to_verified(test_dir.join("x")).is_err());
+    }
+
+    #[test]
+    fn test_read_into() {
+        let test_dir = setup_test_env("test_read_into");
+        fs::write(test_dir.join("long.bin"), vec![1u8; 4096]).unwrap();
+        fs::write(test_dir.join("short.bin"), b"short").unwrap();
+
+        let mut buf = Vec::new();
+        assert_eq!(File::new(test_dir.join("long.bin")).read_into(&mut buf).unwrap(), 4096);
+        let capacity = buf.capacity();
+
+        // 두 번째 읽기는 이전 내용을 지우고 용량을 재사용해야 합니다.
+        assert_eq!(File::new(test_dir.join("short.bin")).read_into(&mut buf).unwrap(), 5);
+        assert_eq!(buf, b"short");
+        assert_eq!(buf.capacity(), capacity);
+    }
+
+    #[test]
+    fn test_matches_glob() {
+        let file = File::new("src/nested/main.rs");
+
+        assert!(file.matches_glob("*.rs"));
+        assert!(!file.matches_glob("*.txt"));
+        assert!(file.matches_glob("ma?n.rs"));
+        assert!(!file.matches_glob("m?n.rs"));
+        // 전체 경로가 아닌 이름만 비교해야 합니다.
+        assert!(!file.matches_glob("src/*"));
+        assert!(!file.matches_glob("*.RS"));
+    }
+
+    #[test]
+    fn test_copy_to_hashing() {
+        let test_dir = setup_test_env("test_copy_to_hashing");
+        let source = File::new(test_dir.join("source.bin")).with_buffer_size(1000);
+        let data: Vec<u8> = (0..50_000u32).map(|i| (i % 253) as u8).collect();
+        fs::write(source.path(), &data).unwrap();
+
+        let (copied, digest) = source.copy_to_hashing(test_dir.join("copy.bin"), HashAlgorithm::Sha512).unwrap();
+        assert_eq!(fs::read(copied.path()).unwrap(), data);
+        assert_eq!(digest, copied.hash_with(HashAlgorithm::Sha512).unwrap());
+        assert_eq!(digest, source.hash_with(HashAlgorithm::Sha512).unwrap());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_write_secure() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let test_dir = setup_test_env("test_write_secure");
+        let path = test_dir.join("secret.key");
+        let file = File::new(&path);
+
+        file.write_secure("token").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "token");
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+
+        // 기존 파일이 더 좁은 권한이면 넓히지 않아야 합니다.
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o400)).unwrap();
+        file.write_secure("rotated").unwrap();
+        assert_eq!(fs::read_to_string(&path).unwrap(), "rotated");
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o400);
+
+        // 기존 파일이 더 넓은 권한이면 0o600으로 좁혀야 합니다.
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o644)).unwrap();
+        file.write_secure("again").unwrap();
+        assert_eq!(fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+    }
+
+    #[test]
+    fn test_walk_breadth_first() {
+        let test_dir = setup_test_env("test_walk_breadth_first");
+        fs::create_dir_all(test_dir.join("a/aa/aaa")).unwrap();
+        fs::create_dir_all(test_dir.join("b/bb")).unwrap();
+        fs::write(test_dir.join("a/aa/aaa/deep.txt"), b"").unwrap();
+        fs::write(test_dir.join("z.txt"), b"").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&test_dir, test_dir.join("b/bb/loop")).unwrap();
+
+        let relative: Vec<PathBuf> = File::new(&test_dir)
+            .walk_breadth_first()
+            .unwrap()
+            .iter()
+            .map(|file| file.path().strip_prefix(&test_dir).unwrap().to_path_buf())
+            .collect();
+
+        // 깊이별로 묶여 있어야 하며, 순환 링크는 따라가지 않아야 합니다.
+        let mut expected = vec!["a", "b", "z.txt", "a/aa", "b/bb", "a/aa/aaa"];
+        #[cfg(unix)]
+        expected.push("b/bb/loop");
+        expected.push("a/aa/aaa/deep.txt");
+        assert_eq!(relative, expected.iter().map(PathBuf::from).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_open_presets() {
+        let test_dir = setup_test_env("test_open_presets");
+        let file = File::new(test_dir.join("data.txt"));
+
+        assert_eq!(file.open_read().unwrap_err().kind(), ErrorKind::NotFound);
+
+        // open_write는 파일을 만들고 기존 내용을 비워야 합니다.
+        file.open_write().unwrap().write_all(b"hello").unwrap();
+        file.open_write().unwrap().write_all(b"hi").unwrap();
+        assert_eq!(fs::read(file.path()).unwrap(), b"hi");
+
+        file.open_append().unwrap().write_all(b" there").unwrap();
+        let mut content = String::new();
+        file.open_read().unwrap().read_to_string(&mut content).unwrap();
+        assert_eq!(content, "hi there");
+        assert!(file.open_read().unwrap().write_all(b"x").is_err());
+
+        // open_rw는 기존 내용을 유지하면서 읽고 쓸 수 있어야 합니다.
+        let mut handle = file.open_rw().unwrap();
+        handle.write_all(b"HI").unwrap();
+        handle.seek(SeekFrom::Start(0)).unwrap();
+        let mut content = String::new();
+        handle.read_to_string(&mut content).unwrap();
+        assert_eq!(content, "HI there");
+    }
+
+    #[test]
+    fn test_cached_hash() {
+        let test_dir = setup_test_env("test_cached_hash");
+        let path = test_dir.join("data.txt");
+        fs::write(&path, "first").unwrap();
+        set_mtime(&path, SystemTime::UNIX_EPOCH + Duration::from_secs(1_000));
+
+        // 읽은 바이트 수를 세어 캐시가 맞을 때 파일을 다시 읽지 않는지 확인합니다.
+        struct CountingReader<'a, R> {
+            inner: R,
+            read: &'a std::cell::Cell<usize>,
+        }
+        impl<R: Read> Read for CountingReader<'_, R> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let n = self.inner.read(buf)?;
+                self.read.set(self.read.get() + n);
+                Ok(n)
+            }
         }
+
+        let file = File::new(&path);
+        let read = std::cell::Cell::new(0);
+        let open = || fs::File::open(&path).map(|inner| CountingReader { inner, read: &read });
+        let mut cache = HashMap::new();
+        let first = file.cached_hash_with(&mut cache, open).unwrap();
+        assert_eq!(first, file.hash());
+        assert_eq!(read.get(), 5);
+
+        // 크기와 수정 시간이 같으면 파일을 전혀 읽지 않아야 합니다.
+        read.set(0);
+        assert_eq!(file.cached_hash_with(&mut cache, open).unwrap(), first);
+        assert_eq!(read.get(), 0);
+
+        // 내용과 수정 시간이 바뀌면 다시 읽어 계산해야 합니다.
+        fs::write(&path, "second").unwrap();
+        set_mtime(&path, SystemTime::UNIX_EPOCH + Duration::from_secs(2_000));
+        let second = file.cached_hash_with(&mut cache, open).unwrap();
+        assert!(read.get() > 0);
+        assert_eq!(second, file.hash());
+        assert_ne!(second, first);
+        assert_eq!(cache[&path].2, second);
+        assert_eq!(file.cached_hash(&mut cache).unwrap(), second);
     }
 
-    /// 해당 경로의 메타데이터를 반환합니다.
-    pub fn metadata(&self) -> Result<Metadata> {
-        metadata(&self.path)
+    #[test]
+    fn test_split_by_lines() {
+        let test_dir = setup_test_env("test_split_by_lines");
+        let path = test_dir.join("app.log");
+        let content: String = (1..=25).map(|i| format!("line {}\n", i)).collect();
+        fs::write(&path, content).unwrap();
+
+        let chunks = File::new(&path).split_by_lines(10, test_dir.join("parts")).unwrap();
+        let counts: Vec<usize> = chunks.iter().map(|chunk| chunk.read_lines().unwrap().len()).collect();
+        assert_eq!(counts, vec![10, 10, 5]);
+        assert_eq!(chunks[0].path(), test_dir.join("parts/app.log.001"));
+        assert_eq!(chunks[2].read_lines().unwrap()[0], "line 21");
+        // 마지막 줄바꿈도 그대로 유지해야 합니다.
+        assert!(fs::read_to_string(chunks[2].path()).unwrap().ends_with("line 25\n"));
+
+        assert_eq!(File::new(&path).split_by_lines(0, &test_dir).unwrap_err().kind(), ErrorKind::InvalidInput);
     }
 
-    /// 해당 경로의 파일 크기를 반환합니다.
-    /// metadata를 사용하므로 파일 혹은 디렉터리가 아니면 오류가 발생합니다.
-    pub fn len(&self) -> Result<u64> {
-        Ok(self.metadata()?.len())
+    #[test]
+    fn test_load_or_compute_hash() {
+        let test_dir = setup_test_env("test_load_or_compute_hash");
+        let path = test_dir.join("data.bin");
+        let cache_path = test_dir.join("hashes.cache");
+        fs::write(&path, b"content").unwrap();
+        set_mtime(&path, SystemTime::UNIX_EPOCH + Duration::from_secs(1_000));
+
+        let file = File::new(&path);
+        let expected = file.hash_with(HashAlgorithm::Sha256).unwrap();
+        assert_eq!(file.load_or_compute_hash(&cache_path, HashAlgorithm::Sha256).unwrap(), expected);
+        assert!(fs::read_to_string(&cache_path).unwrap().starts_with(&expected));
+
+        // 캐시 파일의 해시를 바꿔 두면 두 번째 실행은 캐시에서 읽은 값을 반환해야 합니다.
+        let tampered = fs::read_to_string(&cache_path).unwrap().replace(&expected, "cached-value");
+        fs::write(&cache_path, tampered).unwrap();
+        assert_eq!(file.load_or_compute_hash(&cache_path, HashAlgorithm::Sha256).unwrap(), "cached-value");
+
+        // 알고리즘이 다르면 별도 항목으로 계산해야 합니다.
+        let sha512 = file.load_or_compute_hash(&cache_path, HashAlgorithm::Sha512).unwrap();
+        assert_eq!(sha512, file.hash_with(HashAlgorithm::Sha512).unwrap());
+
+        // 수정 시간이 바뀌면 캐시가 무효화되어야 합니다.
+        set_mtime(&path, SystemTime::UNIX_EPOCH + Duration::from_secs(2_000));
+        assert_eq!(file.load_or_compute_hash(&cache_path, HashAlgorithm::Sha256).unwrap(), expected);
     }
 
-    /// 파일의 SHA-256 해시 값을 반환합니다.
-    /// 파일이 아니거나 오류가 발생하면 빈 문자열을 반환합니다.
-    pub fn hash(&self) -> String {
-        if !self.is_file() {
-            return String::new();
-        }
+    #[test]
+    fn test_identify() {
+        let test_dir = setup_test_env("test_identify");
+        let path = test_dir.join("sample");
+        let identify = |content: &[u8]| {
+            fs::write(&path, content).unwrap();
+            File::new(&path).identify().unwrap()
+        };
 
-        match std::fs::read(&self.path) {
-            Ok(content) => {
-                let mut hasher = Sha256::new();
-                hasher.update(&content);
-                format!("{:x}", hasher.finalize())
-            }
+        assert_eq!(identify(b"\x89PNG\r\n\x1a\n\0\0\0\rIHDR"), FileFormat::Png);
+        assert_eq!(identify(b"PK\x03\x04\x14\0\0\0"), FileFormat::Zip);
+        assert_eq!(identify("안녕하세요, plain text\n".as_bytes()), FileFormat::PlainText);
+        assert_eq!(identify(&[0x13, 0xf7, 0x00, 0x9c, 0xfe, 0x42, 0x88, 0x01]), FileFormat::Unknown);
 
-            Err(_) => String::new(),
-        }
+        // 표본 경계에서 잘린 한글도 텍스트로 판별해야 합니다.
+        let long_text = "가".repeat(5000);
+        assert_eq!(identify(long_text.as_bytes()), FileFormat::PlainText);
     }
 
-    /// 다른 파일과 해시 값을 비교하여 일치하는지 확인합니다.
-    pub fn is_match(&self, other: &File) -> bool {
-        if !self.is_file() || !other.is_file() {
-            return false;
+    #[test]
+    fn test_touch_tree() {
+        let test_dir = setup_test_env("test_touch_tree");
+        fs::create_dir_all(test_dir.join("sub")).unwrap();
+        let old = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
+        for name in ["a.txt", "sub/b.txt", "sub/c.txt"] {
+            fs::write(test_dir.join(name), name).unwrap();
+            set_mtime(&test_dir.join(name), old);
         }
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(test_dir.join("a.txt"), test_dir.join("link")).unwrap();
 
-        self.hash() == other.hash()
+        let before = SystemTime::now() - Duration::from_secs(1);
+        assert_eq!(File::new(&test_dir).touch_tree().unwrap(), 3);
+        for name in ["a.txt", "sub/b.txt", "sub/c.txt"] {
+            assert!(fs::metadata(test_dir.join(name)).unwrap().modified().unwrap() >= before);
+        }
+
+        // 디렉터리를 포함하면 sub도 세어야 합니다.
+        assert_eq!(File::new(&test_dir).touch_tree_with(true).unwrap(), 4);
     }
 
-    /// 다른 파일과 Byte 단위로 비교하여 일치하는지 확인합니다.
-    pub fn is_deep_match(&self, other: &File) -> bool {
-        if !self.is_file() || !other.is_file() {
-            return false;
-        }
+    #[test]
+    fn test_metadata_diff() {
+        let test_dir = setup_test_env("test_metadata_diff");
+        let (a, b) = (File::new(test_dir.join("a.txt")), File::new(test_dir.join("b.txt")));
+        let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1_000_000);
 
-        let self_content = std::fs::read(&self.path);
-        let other_content = std::fs::read(&other.path);
+        // 수정 시간만 다른 경우입니다.
+        a.create_with_times("same", time).unwrap();
+        b.create_with_times("same", time + Duration::from_secs(60)).unwrap();
+        let diff = a.metadata_diff(&b).unwrap();
+        assert!(diff.modified_differs && diff.differs());
+        assert!(!diff.size_differs && !diff.permissions_differ && !diff.file_type_differs);
+        assert_eq!(diff.modified, (time, time + Duration::from_secs(60)));
 
-        match (self_content, other_content) {
-            (Ok(a), Ok(b)) => a == b,
-            _ => false,
+        // 크기만 다른 경우입니다.
+        b.create_with_times("longer", time).unwrap();
+        let diff = a.metadata_diff(&b).unwrap();
+        assert!(diff.size_differs && !diff.modified_differs);
+        assert_eq!(diff.sizes, (4, 6));
+
+        assert!(!a.metadata_diff(&a).unwrap().differs());
+        assert!(a.metadata_diff(&File::new(&test_dir)).unwrap().file_type_differs);
+    }
+
+    #[test]
+    fn test_rm_older_than() {
+        let test_dir = setup_test_env("test_rm_older_than");
+        fs::create_dir_all(test_dir.join("old_only")).unwrap();
+        fs::create_dir_all(test_dir.join("mixed")).unwrap();
+        let old = SystemTime::now() - Duration::from_secs(7 * 24 * 3600);
+        for name in ["old.txt", "old_only/a.txt", "mixed/b.txt"] {
+            fs::write(test_dir.join(name), name).unwrap();
+            set_mtime(&test_dir.join(name), old);
         }
+        fs::write(test_dir.join("fresh.txt"), b"fresh").unwrap();
+        fs::write(test_dir.join("mixed/fresh.txt"), b"fresh").unwrap();
+
+        let root = File::new(&test_dir);
+        assert_eq!(root.rm_older_than(Duration::from_secs(24 * 3600)).unwrap(), 3);
+        assert!(!test_dir.join("old.txt").exists() && !test_dir.join("mixed/b.txt").exists());
+        assert!(test_dir.join("fresh.txt").exists() && test_dir.join("mixed/fresh.txt").exists());
+        // 기본 동작은 비게 된 디렉터리를 남겨 두어야 합니다.
+        assert!(test_dir.join("old_only").exists());
+
+        assert_eq!(root.rm_older_than_with(Duration::from_secs(24 * 3600), true).unwrap(), 0);
+        assert!(!test_dir.join("old_only").exists());
+        assert!(test_dir.join("mixed").exists());
     }
 
-    /// 경로가 파일을 가리키는지 확인합니다.
-    pub fn is_file(&self) -> bool {
-        self.path.is_file()
+    #[cfg(unix)]
+    #[test]
+    fn test_hard_link_count() {
+        let test_dir = setup_test_env("test_hard_link_count");
+        let file = File::new(test_dir.join("original.txt"));
+        fs::write(file.path(), b"shared").unwrap();
+        assert_eq!(file.hard_link_count().unwrap(), 1);
+
+        fs::hard_link(file.path(), test_dir.join("link.txt")).unwrap();
+        assert_eq!(file.hard_link_count().unwrap(), 2);
+
+        fs::remove_file(test_dir.join("link.txt")).unwrap();
+        assert_eq!(file.hard_link_count().unwrap(), 1);
     }
 
-    /// 경로가 디렉토리를 가리키는지 확인합니다.
-    pub fn is_directory(&self) -> bool {
-        self.path.is_dir()
+    #[test]
+    fn test_write_lines() {
+        let test_dir = setup_test_env("test_write_lines");
+        let file = File::new(test_dir.join("lines.txt"));
+        let lines = vec!["first".to_string(), "second".to_string()];
+
+        file.write_lines(&lines, LineEnding::Lf).unwrap();
+        assert_eq!(fs::read_to_string(file.path()).unwrap(), "first\nsecond\n");
+        assert_eq!(file.read_lines().unwrap(), lines);
+
+        file.write_lines(["a", "b", "c"], LineEnding::CrLf).unwrap();
+        assert_eq!(fs::read_to_string(file.path()).unwrap(), "a\r\nb\r\nc\r\n");
+        assert_eq!(file.line_ending().unwrap(), LineEnding::CrLf);
+        assert_eq!(file.read_lines().unwrap(), vec!["a", "b", "c"]);
+
+        // None은 마지막 줄바꿈을 생략해야 합니다.
+        file.write_lines(["a", "b"], LineEnding::None).unwrap();
+        assert_eq!(fs::read_to_string(file.path()).unwrap(), "a\nb");
+
+        assert_eq!(file.write_lines(["a"], LineEnding::Mixed).unwrap_err().kind(), ErrorKind::InvalidInput);
     }
 
-    /// 경로가 존재하는지 확인합니다.
-    pub fn exists(&self) -> bool {
-        self.path.exists()
+    #[test]
+    fn test_diff_lines() {
+        let test_dir = setup_test_env("test_diff_lines");
+        let old = File::new(test_dir.join("old.conf"));
+        let new = File::new(test_dir.join("new.conf"));
+        fs::write(old.path(), "host=a\nport=80\nmode=dev\n").unwrap();
+        fs::write(new.path(), "host=a\nport=8080\nmode=dev\n").unwrap();
+
+        let expected = vec![
+            LineChange::Unchanged("host=a".to_string()),
+            LineChange::Removed("port=80".to_string()),
+            LineChange::Added("port=8080".to_string()),
+            LineChange::Unchanged("mode=dev".to_string()),
+        ];
+        assert_eq!(old.diff_lines(&new).unwrap(), expected);
+
+        // 끝에 추가된 줄입니다.
+        fs::write(new.path(), "host=a\nport=80\nmode=dev\nextra=1\n").unwrap();
+        let changes = old.diff_lines(&new).unwrap();
+        assert_eq!(changes.last(), Some(&LineChange::Added("extra=1".to_string())));
+        assert_eq!(changes.len(), 4);
     }
 
-    /// 해당 경로의 파일 및 디렉터리를 삭제합니다.
-    pub fn rm(&self) -> Result<()> {
-        if self.is_file() {
-            std::fs::remove_file(&self.path)?;
-        } else if self.is_directory() {
-            std::fs::remove_dir_all(&self.path)?;
+    #[cfg(unix)]
+    #[test]
+    fn test_is_same_filesystem() {
+        let test_dir = setup_test_env("test_is_same_filesystem");
+        fs::create_dir(test_dir.join("sub")).unwrap();
+        fs::write(test_dir.join("a.txt"), b"a").unwrap();
+        fs::write(test_dir.join("sub/b.txt"), b"b").unwrap();
+
+        let a = File::new(test_dir.join("a.txt"));
+        assert!(a.is_same_filesystem(&File::new(test_dir.join("sub/b.txt"))).unwrap());
+        assert!(a.is_same_filesystem(&File::new(&test_dir)).unwrap());
+        assert!(a.is_same_filesystem(&File::new(test_dir.join("missing"))).is_err());
+    }
+
+    #[test]
+    fn test_resume_copy_to() {
+        let test_dir = setup_test_env("test_resume_copy_to");
+        let data: Vec<u8> = (0..5000u32).map(|i| (i % 251) as u8).collect();
+        fs::write(test_dir.join("src.bin"), &data).unwrap();
+        let source = File::new(test_dir.join("src.bin")).with_buffer_size(64);
+
+        // 대상이 없으면 새로 복사해야 합니다.
+        source.resume_copy_to(test_dir.join("fresh.bin")).unwrap();
+        assert_eq!(fs::read(test_dir.join("fresh.bin")).unwrap(), data);
+
+        // 앞부분이 같으면 나머지만 이어 붙여야 합니다.
+        fs::write(test_dir.join("partial.bin"), &data[..1234]).unwrap();
+        source.resume_copy_to(test_dir.join("partial.bin")).unwrap();
+        assert_eq!(fs::read(test_dir.join("partial.bin")).unwrap(), data);
+
+        // 앞부분이 다르면 처음부터 다시 복사해야 합니다.
+        fs::write(test_dir.join("bad.bin"), b"not a prefix").unwrap();
+        source.resume_copy_to(test_dir.join("bad.bin")).unwrap();
+        assert_eq!(fs::read(test_dir.join("bad.bin")).unwrap(), data);
+    }
+
+    #[test]
+    fn test_with_lock() {
+        let test_dir = setup_test_env("test_with_lock");
+        let lock_path = test_dir.join("lock");
+        let active = std::sync::Arc::new(AtomicUsize::new(0));
+
+        let handles: Vec<_> = (0..2)
+            .map(|_| {
+                let file = File::new(&lock_path);
+                let active = active.clone();
+                std::thread::spawn(move || {
+                    for _ in 0..5 {
+                        file.with_lock(|| {
+                            // 임계 구역 안에는 항상 한 스레드만 있어야 합니다.
+                            assert_eq!(active.fetch_add(1, Ordering::SeqCst), 0);
+                            std::thread::sleep(Duration::from_millis(5));
+                            active.fetch_sub(1, Ordering::SeqCst);
+                        })
+                        .unwrap();
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
         }
 
-        Ok(())
+        // 클로저가 패닉해도 잠금이 해제되어야 합니다.
+        let file = File::new(&lock_path);
+        let result = std::panic::catch_unwind(|| file.with_lock(|| panic!("실패")));
+        assert!(result.is_err());
+        assert_eq!(file.with_lock(|| 42).unwrap(), 42);
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::fs;
+    #[test]
+    fn test_count_files_and_dirs() {
+        let test_dir = setup_test_env("test_count_files_and_dirs");
+        fs::create_dir_all(test_dir.join("a/aa")).unwrap();
+        fs::create_dir(test_dir.join("b")).unwrap();
+        fs::write(test_dir.join("1.txt"), b"1").unwrap();
+        fs::write(test_dir.join("2.txt"), b"2").unwrap();
+        fs::write(test_dir.join("a/3.txt"), b"3").unwrap();
+        fs::write(test_dir.join("a/aa/4.txt"), b"4").unwrap();
 
-    // 테스트용 임시 디렉터리 경로를 생성하고 정리합니다.
-    fn setup_test_env(test_name: &str) -> PathBuf {
-        let temp_dir = std::env::temp_dir().join("files_test").join(test_name);
-        if temp_dir.exists() {
-            fs::remove_dir_all(&temp_dir).unwrap();
+        let dir = File::new(&test_dir);
+        assert_eq!(dir.count_files().unwrap(), 2);
+        assert_eq!(dir.count_dirs().unwrap(), 2);
+        assert_eq!(dir.count_files_recursive().unwrap(), 4);
+        assert_eq!(dir.count_dirs_recursive().unwrap(), 3);
+    }
+
+    #[test]
+    fn test_move_to_refuses_overwrite() {
+        let test_dir = setup_test_env("test_move_to_refuses_overwrite");
+        let source_path = test_dir.join("source.txt");
+        let dest_path = test_dir.join("dest.txt");
+        fs::write(&source_path, b"source").unwrap();
+        fs::write(&dest_path, b"dest").unwrap();
+
+        // 대상이 있으면 AlreadyExists를 반환하고 두 파일 모두 그대로 남아야 합니다.
+        let mut file = File::new(&source_path);
+        assert_eq!(file.move_to(&dest_path).unwrap_err().kind(), ErrorKind::AlreadyExists);
+        assert_eq!(file.path(), source_path);
+        assert_eq!(fs::read(&source_path).unwrap(), b"source");
+        assert_eq!(fs::read(&dest_path).unwrap(), b"dest");
+
+        // move_to_overwrite는 대상을 덮어써야 합니다.
+        file.move_to_overwrite(&dest_path).unwrap();
+        assert_eq!(file.path(), dest_path);
+        assert!(!source_path.exists());
+        assert_eq!(fs::read(&dest_path).unwrap(), b"source");
+
+        // 비어 있는 디렉터리도 덮어쓰지 않아야 합니다.
+        fs::create_dir_all(test_dir.join("dir_a/inner")).unwrap();
+        fs::create_dir(test_dir.join("dir_b")).unwrap();
+        let mut dir = File::new(test_dir.join("dir_a"));
+        assert_eq!(dir.move_to(test_dir.join("dir_b")).unwrap_err().kind(), ErrorKind::AlreadyExists);
+        assert!(test_dir.join("dir_a/inner").is_dir());
+        dir.move_to(test_dir.join("dir_c")).unwrap();
+        assert!(test_dir.join("dir_c/inner").is_dir());
+    }
+
+    #[test]
+    fn test_digest_matches() {
+        let test_dir = setup_test_env("test_digest_matches");
+        let file_path = test_dir.join("data.txt");
+        fs::write(&file_path, b"hello").unwrap();
+        let file = File::new(&file_path);
+
+        let mut hasher = Hasher::new(HashAlgorithm::Sha256);
+        hasher.update(b"hello");
+        let expected = hasher.finalize_bytes();
+
+        assert!(file.digest_matches(HashAlgorithm::Sha256, &expected).unwrap());
+
+        // 한 바이트라도 다르거나 길이가 다르면 false를 반환해야 합니다.
+        let mut wrong = expected.clone();
+        wrong[0] ^= 0xff;
+        assert!(!file.digest_matches(HashAlgorithm::Sha256, &wrong).unwrap());
+        assert!(!file.digest_matches(HashAlgorithm::Sha256, &expected[..16]).unwrap());
+    }
+
+    #[test]
+    fn test_flatten_into() {
+        let test_dir = setup_test_env("test_flatten_into");
+        fs::create_dir_all(test_dir.join("tree/a")).unwrap();
+        fs::create_dir_all(test_dir.join("tree/b")).unwrap();
+        fs::write(test_dir.join("tree/a/same.txt"), b"a").unwrap();
+        fs::write(test_dir.join("tree/b/same.txt"), b"b").unwrap();
+
+        let flat = test_dir.join("flat");
+        let copied = File::new(test_dir.join("tree")).flatten_into(&flat).unwrap();
+
+        // 같은 이름의 두 파일이 서로 다른 이름으로 복사되어야 합니다.
+        assert_eq!(copied.len(), 2);
+        assert_eq!(fs::read(flat.join("same.txt")).unwrap(), b"a");
+        assert_eq!(fs::read(flat.join("same (1).txt")).unwrap(), b"b");
+        assert_eq!(fs::read_dir(&flat).unwrap().count(), 2);
+    }
+
+    #[test]
+    fn test_depth() {
+        assert_eq!(File::new("a/b/c").depth(), 3);
+        assert_eq!(File::new("./a").depth(), 1);
+        assert_eq!(File::new("").depth(), 0);
+        #[cfg(unix)]
+        assert_eq!(File::new("/usr/local/bin").depth(), 3);
+        #[cfg(windows)]
+        assert_eq!(File::new(r"C:\Users\me").depth(), 2);
+    }
+
+    #[test]
+    fn test_depth_from() {
+        let base = File::new("root/base");
+        assert_eq!(File::new("root/base/x/y.txt").depth_from(&base), Some(2));
+        assert_eq!(File::new("root/base").depth_from(&base), Some(0));
+        assert_eq!(File::new("root/other/x").depth_from(&base), None);
+    }
+
+    #[test]
+    fn test_increment_counter() {
+        let test_dir = setup_test_env("test_increment_counter");
+        let counter_path = test_dir.join("counter");
+
+        let handles: Vec<_> = (0..4)
+            .map(|_| {
+                let counter = File::new(&counter_path);
+                std::thread::spawn(move || {
+                    for _ in 0..25 {
+                        counter.increment_counter().unwrap();
+                    }
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            handle.join().unwrap();
         }
-        fs::create_dir_all(&temp_dir).unwrap();
-        temp_dir
+
+        // 동시에 증가시켜도 유실된 갱신이 없어야 합니다.
+        assert_eq!(fs::read_to_string(&counter_path).unwrap(), "100");
+        assert_eq!(File::new(&counter_path).increment_counter().unwrap(), 101);
     }
 
     #[test]
-    fn test_file_creation_and_checks() {
-        let test_dir = setup_test_env("test_file_creation_and_checks");
-        let file_path = test_dir.join("test_file.txt");
-        let dir_path = test_dir.join("test_dir");
-        fs::File::create(&file_path).unwrap();
-        fs::create_dir(&dir_path).unwrap();
+    fn test_tree_compare() {
+        let test_dir = setup_test_env("test_tree_compare");
+        fs::create_dir_all(test_dir.join("a/sub")).unwrap();
+        fs::create_dir_all(test_dir.join("b/sub")).unwrap();
+        fs::write(test_dir.join("a/same.txt"), b"same").unwrap();
+        fs::write(test_dir.join("b/same.txt"), b"same").unwrap();
+        fs::write(test_dir.join("a/sub/changed.txt"), b"old").unwrap();
+        fs::write(test_dir.join("b/sub/changed.txt"), b"new").unwrap();
+        fs::write(test_dir.join("a/removed.txt"), b"r").unwrap();
+        fs::write(test_dir.join("b/added.txt"), b"a").unwrap();
+
+        let report = File::new(test_dir.join("a")).tree_compare(&File::new(test_dir.join("b"))).unwrap();
+        assert_eq!(report.only_in_self, vec![PathBuf::from("removed.txt")]);
+        assert_eq!(report.only_in_other, vec![PathBuf::from("added.txt")]);
+        // 크기가 같아도 내용이 다르면 해시 비교로 찾아내야 합니다.
+        assert_eq!(report.content_differs, vec![PathBuf::from("sub").join("changed.txt")]);
+    }
 
+    #[test]
+    fn test_normalize_newlines() {
+        let test_dir = setup_test_env("test_normalize_newlines");
+        let file_path = test_dir.join("text.txt");
+        fs::write(&file_path, b"a\r\nb\nc\r\n").unwrap();
         let file = File::new(&file_path);
-        let dir = File::new(&dir_path);
-        let non_existent = File::new("non_existent_file.txt");
 
-        // exists, is_file, is_dir 테스트
-        assert!(file.exists());
-        assert!(file.is_file());
-        assert!(!file.is_directory());
+        // 섞인 줄바꿈을 LF로 변환해야 합니다.
+        assert!(file.normalize_newlines(LineEnding::Lf).unwrap());
+        assert_eq!(fs::read(&file_path).unwrap(), b"a\nb\nc\n");
 
-        assert!(dir.exists());
-        assert!(dir.is_directory());
-        assert!(!dir.is_file());
+        // 이미 LF이면 다시 쓰지 않으므로 수정 시간이 유지되어야 합니다.
+        let past = SystemTime::now() - Duration::from_secs(3600);
+        set_mtime(&file_path, past);
+        assert!(!file.normalize_newlines(LineEnding::Lf).unwrap());
+        assert_eq!(fs::metadata(&file_path).unwrap().modified().unwrap(), past);
 
-        assert!(!non_existent.exists());
+        assert!(file.normalize_newlines(LineEnding::CrLf).unwrap());
+        assert_eq!(fs::read(&file_path).unwrap(), b"a\r\nb\r\nc\r\n");
+        assert_eq!(file.normalize_newlines(LineEnding::None).unwrap_err().kind(), ErrorKind::InvalidInput);
     }
 
     #[test]
-    fn test_rm_file() {
-        let test_dir = setup_test_env("test_rm_file");
-        let file_path = test_dir.join("file_to_delete.txt");
-        fs::File::create(&file_path).unwrap();
+    fn test_is_deep_match_prefix() {
+        let test_dir = setup_test_env("test_is_deep_match_prefix");
+        fs::write(test_dir.join("a.bin"), b"shared-prefix-AAAA").unwrap();
+        fs::write(test_dir.join("b.bin"), b"shared-prefix-BB").unwrap();
+        let a = File::new(test_dir.join("a.bin")).with_buffer_size(4);
+        let b = File::new(test_dir.join("b.bin"));
 
+        // 공통 부분까지는 같고, 그 뒤부터는 달라야 합니다.
+        assert!(a.is_deep_match_prefix(&b, 14).unwrap());
+        assert!(a.is_deep_match_prefix(&b, 0).unwrap());
+        assert!(!a.is_deep_match_prefix(&b, 15).unwrap());
+
+        // 짧은 파일의 길이를 넘어서면 false를 반환해야 합니다.
+        assert!(!a.is_deep_match_prefix(&b, 100).unwrap());
+        assert!(a.is_deep_match_prefix(&a, 100).unwrap());
+    }
+
+    #[test]
+    fn test_hash_with_progress() {
+        let test_dir = setup_test_env("test_hash_with_progress");
+        let file_path = test_dir.join("big.bin");
+        fs::write(&file_path, vec![0xabu8; 1024 * 1024]).unwrap();
         let file = File::new(&file_path);
-        assert!(file.exists());
 
-        // rm 메서드로 파일 삭제
-        file.rm().unwrap();
-        assert!(!file.exists());
+        let mut reports = Vec::new();
+        let hash = file
+            .hash_with_progress(HashAlgorithm::Sha256, |done, total| reports.push((done, total)))
+            .unwrap();
+
+        // 기본 버퍼(64 KiB) 단위로 여러 번 호출되고, 마지막에는 전체 크기를 보고해야 합니다.
+        assert!(reports.len() > 1);
+        assert_eq!(reports.last(), Some(&(1024 * 1024, 1024 * 1024)));
+        assert_eq!(hash, file.hash_with(HashAlgorithm::Sha256).unwrap());
     }
 
     #[test]
-    fn test_rm_dir() {
-        let test_dir = setup_test_env("test_rm_dir");
-        let dir_path = test_dir.join("dir_to_delete");
-        fs::create_dir_all(&dir_path).unwrap();
-        // 디렉터리 안에 파일 생성
-        fs::File::create(dir_path.join("inner_file.txt")).unwrap();
+    fn test_rm_reporting() {
+        let test_dir = setup_test_env("test_rm_reporting");
+        fs::write(test_dir.join("single.bin"), vec![0u8; 100]).unwrap();
+        fs::create_dir_all(test_dir.join("tree/sub")).unwrap();
+        fs::write(test_dir.join("tree/a.bin"), vec![0u8; 30]).unwrap();
+        fs::write(test_dir.join("tree/sub/b.bin"), vec![0u8; 12]).unwrap();
 
-        let dir = File::new(&dir_path);
-        assert!(dir.exists());
+        let single = File::new(test_dir.join("single.bin"));
+        assert_eq!(single.rm_reporting().unwrap(), 100);
+        assert!(!single.exists());
 
-        // rm 메서드로 디렉터리 재귀적으로 삭제
-        dir.rm().unwrap();
-        assert!(!dir.exists());
+        let tree = File::new(test_dir.join("tree"));
+        assert_eq!(tree.rm_reporting().unwrap(), 42);
+        assert!(!tree.exists());
+
+        // 없는 경로는 0을 반환해야 합니다.
+        assert_eq!(tree.rm_reporting().unwrap(), 0);
     }
 
     #[test]
-    fn test_rm_non_existent() {
-        let non_existent_file = File::new("path/that/does/not/exist.tmp");
-        // 존재하지 않는 파일을 삭제 시도 시 에러가 발생하지 않고 Ok(())를 반환해야 합니다.
-        // is_file()과 is_dir()가 모두 false이므로 rm()은 아무 작업도 하지 않습니다.
-        assert!(non_existent_file.rm().is_ok());
+    fn test_git_blob_hash() {
+        let test_dir = setup_test_env("test_git_blob_hash");
+        let file_path = test_dir.join("hello.txt");
+
+        // `echo hello | git hash-object --stdin`
+        fs::write(&file_path, b"hello\n").unwrap();
+        assert_eq!(File::new(&file_path).git_blob_hash().unwrap(), "ce013625030ba8dba906f756967f9e9ca394464a");
+
+        // 빈 블롭의 잘 알려진 ID와 같아야 합니다.
+        fs::write(&file_path, b"").unwrap();
+        assert_eq!(File::new(&file_path).git_blob_hash().unwrap(), "e69de29bb2d1d6434b8b29ae775ad8c2e48c5391");
     }
 
     #[test]
-    fn test_file_len() {
-        let test_dir = setup_test_env("test_file_len");
-        let file_path = test_dir.join("file_with_content.txt");
-        let content = b"Hello, World!";
-        fs::write(&file_path, content).unwrap();
+    fn test_prune_empty_dirs() {
+        let test_dir = setup_test_env("test_prune_empty_dirs");
+        fs::create_dir_all(test_dir.join("a/b/c")).unwrap();
+        fs::create_dir_all(test_dir.join("a/d")).unwrap();
+        fs::create_dir_all(test_dir.join("keep/empty")).unwrap();
+        fs::write(test_dir.join("keep/file.txt"), b"x").unwrap();
+
+        let root = File::new(&test_dir);
+        assert_eq!(root.prune_empty_dirs().unwrap(), 5);
+        assert!(!test_dir.join("a").exists());
+        assert!(!test_dir.join("keep/empty").exists());
+        assert!(test_dir.join("keep/file.txt").exists());
+
+        // 루트는 비어도 남아 있어야 합니다.
+        let empty_root = File::new(test_dir.join("keep/sub"));
+        fs::create_dir(empty_root.path()).unwrap();
+        assert_eq!(empty_root.prune_empty_dirs().unwrap(), 0);
+        assert!(empty_root.exists());
+    }
 
+    #[test]
+    fn test_read_range_exact() {
+        let test_dir = setup_test_env("test_read_range_exact");
+        let file_path = test_dir.join("data.bin");
+        fs::write(&file_path, b"0123456789").unwrap();
         let file = File::new(&file_path);
-        assert_eq!(file.len().unwrap(), content.len() as u64);
+
+        assert_eq!(file.read_range_exact(2, 3).unwrap(), b"234");
+        assert_eq!(file.read_range_exact(0, 10).unwrap(), b"0123456789");
+
+        // 파일 끝을 넘는 위치와 끝에 걸친 범위는 UnexpectedEof를 반환해야 합니다.
+        assert_eq!(file.read_range_exact(20, 1).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+        assert_eq!(file.read_range_exact(8, 5).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+
+        // 관대한 read_range는 남은 만큼만 반환해야 합니다.
+        assert_eq!(file.read_range(8, 5).unwrap(), b"89");
+        assert!(file.read_range(20, 1).unwrap().is_empty());
+
+        // 헤더에서 읽은 터무니없는 길이도 할당 없이 처리되어야 합니다.
+        assert_eq!(file.read_range_exact(0, usize::MAX).unwrap_err().kind(), ErrorKind::UnexpectedEof);
+        assert_eq!(file.read_range(0, usize::MAX).unwrap(), b"0123456789");
     }
 
+    #[cfg(unix)]
     #[test]
-    fn test_is_match() {
-        let test_dir = setup_test_env("test_is_match");
-        let file1_path = test_dir.join("file1.txt");
-        let file2_path = test_dir.join("file2.txt");
-        let file3_path = test_dir.join("file3.txt");
+    fn test_link_size() {
+        let test_dir = setup_test_env("test_link_size");
+        let target = test_dir.join("target.bin");
+        fs::write(&target, vec![0u8; 4096]).unwrap();
+        let link = test_dir.join("link");
+        std::os::unix::fs::symlink(&target, &link).unwrap();
 
-        fs::write(&file1_path, b"Hello, World!").unwrap();
-        fs::write(&file2_path, b"Hello, World!").unwrap();
-        fs::write(&file3_path, b"Different content").unwrap();
+        // 대상 파일의 크기가 아니라 대상 경로의 길이여야 합니다.
+        let file = File::new(&link);
+        assert_eq!(file.link_size().unwrap(), target.as_os_str().len() as u64);
+        assert_eq!(file.len().unwrap(), 4096);
+    }
 
-        let file1 = File::new(&file1_path);
-        let file2 = File::new(&file2_path);
-        let file3 = File::new(&file3_path);
+    #[test]
+    fn test_read_dir_natural() {
+        let test_dir = setup_test_env("test_read_dir_natural");
+        for name in ["a10", "a2", "a20", "a1", "b"] {
+            fs::write(test_dir.join(name), b"").unwrap();
+        }
 
-        assert!(file1.is_match(&file2));
-        assert!(!file1.is_match(&file3));
+        let names: Vec<String> = File::new(&test_dir)
+            .read_dir_natural()
+            .unwrap()
+            .iter()
+            .map(|f| f.path().file_name().unwrap().to_string_lossy().into_owned())
+            .collect();
+        assert_eq!(names, ["a1", "a2", "a10", "a20", "b"]);
     }
 
     #[test]
-    fn test_is_deep_match() {
-        let test_dir = setup_test_env("test_is_deep_match");
-        let file1_path = test_dir.join("file1.txt");
-        let file2_path = test_dir.join("file2.txt");
-        let file3_path = test_dir.join("file3.txt");
+    fn test_natural_cmp() {
+        use std::cmp::Ordering;
+        assert_eq!(natural_cmp("file2", "file10"), Ordering::Less);
+        assert_eq!(natural_cmp("v1.10", "v1.9"), Ordering::Greater);
+        // 값이 같은 숫자는 원래 문자열 순서로 정해야 합니다.
+        assert_eq!(natural_cmp("a01", "a1"), Ordering::Less);
+        assert_eq!(natural_cmp("a", "a1"), Ordering::Less);
+    }
 
-        fs::write(&file1_path, b"Hello, World!").unwrap();
-        fs::write(&file2_path, b"Hello, World!").unwrap();
-        fs::write(&file3_path, b"Different content").unwrap();
+    #[test]
+    fn test_hash_text() {
+        let test_dir = setup_test_env("test_hash_text");
+        fs::write(test_dir.join("bom.txt"), b"\xEF\xBB\xBFhello\r\nworld\r\n").unwrap();
+        fs::write(test_dir.join("plain.txt"), b"hello\r\nworld\r\n").unwrap();
+        fs::write(test_dir.join("lf.txt"), b"hello\nworld\n").unwrap();
+        let bom = File::new(test_dir.join("bom.txt")).with_buffer_size(4);
+        let plain = File::new(test_dir.join("plain.txt"));
+        let lf = File::new(test_dir.join("lf.txt"));
 
-        let file1 = File::new(&file1_path);
-        let file2 = File::new(&file2_path);
-        let file3 = File::new(&file3_path);
+        // BOM 유무와 관계없이 같은 해시여야 합니다.
+        assert_eq!(bom.hash_text(HashAlgorithm::Sha256).unwrap(), plain.hash_text(HashAlgorithm::Sha256).unwrap());
+        assert_ne!(plain.hash_text(HashAlgorithm::Sha256).unwrap(), lf.hash_text(HashAlgorithm::Sha256).unwrap());
 
-        assert!(file1.is_deep_match(&file2));
-        assert!(!file1.is_deep_match(&file3));
+        // 줄바꿈까지 정규화하면 LF 파일과도 같아야 합니다.
+        let expected = lf.hash_with(HashAlgorithm::Sha256).unwrap();
+        assert_eq!(bom.hash_text_with(HashAlgorithm::Sha256, true).unwrap(), expected);
+        assert_eq!(plain.hash_text_with(HashAlgorithm::Sha256, true).unwrap(), expected);
+    }
+
+    #[test]
+    fn test_copy_to_writer() {
+        let test_dir = setup_test_env("test_copy_to_writer");
+        let file_path = test_dir.join("data.bin");
+        let data: Vec<u8> = (0..1000u32).map(|i| (i % 256) as u8).collect();
+        fs::write(&file_path, &data).unwrap();
+
+        let mut output = Vec::new();
+        let written = File::new(&file_path).with_buffer_size(64).copy_to_writer(&mut output).unwrap();
+        assert_eq!(written, 1000);
+        assert_eq!(output, data);
+    }
+
+    #[test]
+    fn test_set_exact_size() {
+        let test_dir = setup_test_env("test_set_exact_size");
+
+        // 작은 파일은 0으로 채워 늘려야 합니다.
+        let small = File::new(test_dir.join("small.bin"));
+        fs::write(small.path(), b"abc").unwrap();
+        small.set_exact_size(8).unwrap();
+        assert_eq!(small.len().unwrap(), 8);
+        assert_eq!(fs::read(small.path()).unwrap(), b"abc\0\0\0\0\0");
+
+        // 큰 파일은 잘라 줄여야 합니다.
+        let large = File::new(test_dir.join("large.bin"));
+        fs::write(large.path(), vec![1u8; 4096]).unwrap();
+        large.set_exact_size(10).unwrap();
+        assert_eq!(large.len().unwrap(), 10);
+
+        // 없는 파일은 새로 만들어야 합니다.
+        let created = File::new(test_dir.join("new.bin"));
+        created.set_exact_size(1024).unwrap();
+        assert_eq!(created.len().unwrap(), 1024);
+    }
+
+    #[test]
+    fn test_is_text_match_normalized() {
+        let test_dir = setup_test_env("test_is_text_match_normalized");
+        fs::write(test_dir.join("expected.rs"), "fn main() {\n    run();\n}\n").unwrap();
+        fs::write(test_dir.join("generated.rs"), "\u{feff}fn main() {  \r\n    run();\t\r\n}\r\n").unwrap();
+        fs::write(test_dir.join("different.rs"), "fn main() {\n    stop();\n}\n").unwrap();
+        fs::write(test_dir.join("no_newline.rs"), "fn main() {\n    run();\n}").unwrap();
+        let expected = File::new(test_dir.join("expected.rs"));
+
+        // BOM, 줄 끝 공백, 줄바꿈 방식만 다르면 같아야 합니다.
+        assert!(expected.is_text_match_normalized(&File::new(test_dir.join("generated.rs"))).unwrap());
+        assert!(!expected.is_text_match_normalized(&File::new(test_dir.join("different.rs"))).unwrap());
+
+        // 마지막 줄바꿈은 선택적으로 무시할 수 있어야 합니다.
+        let no_newline = File::new(test_dir.join("no_newline.rs"));
+        assert!(!expected.is_text_match_normalized(&no_newline).unwrap());
+        assert!(expected.is_text_match_normalized_with(&no_newline, true).unwrap());
+    }
+
+    #[test]
+    fn test_next_available() {
+        let test_dir = setup_test_env("test_next_available");
+        let free = File::new(test_dir.join("free.txt"));
+        assert_eq!(free.next_available(), free);
+
+        fs::write(test_dir.join("foo.txt"), b"").unwrap();
+        fs::write(test_dir.join("foo (1).txt"), b"").unwrap();
+        let next = File::new(test_dir.join("foo.txt")).next_available();
+        assert_eq!(next.path(), test_dir.join("foo (2).txt"));
     }
 }
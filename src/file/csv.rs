@@ -0,0 +1,52 @@
+use std::io::{Error, ErrorKind, Result};
+
+use serde::de::DeserializeOwned;
+
+use super::File;
+
+impl File {
+    /// 첫 줄을 헤더로 사용하여 파일을 CSV로 파싱하고, 각 행을 `T`로 역직렬화하여 반환합니다.
+    /// 파싱이나 역직렬화에 실패하면 `InvalidData` 오류를 반환합니다.
+    pub fn read_csv<T: DeserializeOwned>(&self) -> Result<Vec<T>> {
+        // 내용을 먼저 읽어 두므로 파서에서 발생하는 오류는 모두 데이터 오류입니다.
+        let content = std::fs::read(&self.path)?;
+        let mut reader = ::csv::Reader::from_reader(content.as_slice());
+
+        reader
+            .deserialize()
+            .map(|record| record.map_err(|e| Error::new(ErrorKind::InvalidData, e)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::tests::setup_test_env;
+    use serde::Deserialize;
+    use std::fs;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    struct Record {
+        name: String,
+        age: u32,
+    }
+
+    #[test]
+    fn test_read_csv() {
+        let test_dir = setup_test_env("test_read_csv");
+        let file_path = test_dir.join("people.csv");
+        fs::write(&file_path, "name,age\nalice,30\nbob,25\n").unwrap();
+
+        let records: Vec<Record> = File::new(&file_path).read_csv().unwrap();
+        assert_eq!(records, vec![
+            Record { name: "alice".to_string(), age: 30 },
+            Record { name: "bob".to_string(), age: 25 },
+        ]);
+
+        // 타입이 맞지 않는 값은 InvalidData를 반환해야 합니다.
+        fs::write(&file_path, "name,age\ncarol,old\n").unwrap();
+        let err = File::new(&file_path).read_csv::<Record>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+}
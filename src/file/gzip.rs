@@ -0,0 +1,104 @@
+use std::io::{BufRead, BufReader, Error, ErrorKind, Read, Result};
+
+use flate2::read::MultiGzDecoder;
+
+use super::File;
+
+// gzip 파일의 매직 바이트입니다.
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+
+// 압축 해제 중 발생한 형식 오류를 `InvalidData`로 통일합니다.
+fn to_invalid_data(e: Error) -> Error {
+    match e.kind() {
+        ErrorKind::InvalidInput | ErrorKind::UnexpectedEof => Error::new(ErrorKind::InvalidData, e),
+        _ => e,
+    }
+}
+
+impl File {
+    // 매직 바이트를 확인한 뒤 파일 스트림 위에 압축 해제기를 연결합니다.
+    // `cat a.gz b.gz`처럼 여러 멤버가 이어진 파일도 끝까지 풀 수 있도록 모든 멤버를 차례로 읽습니다.
+    fn open_gzip(&self) -> Result<MultiGzDecoder<BufReader<std::fs::File>>> {
+        let mut reader = BufReader::with_capacity(self.buffer_size, std::fs::File::open(&self.path)?);
+        if !reader.fill_buf()?.starts_with(&GZIP_MAGIC) {
+            return Err(Error::new(
+                ErrorKind::InvalidData,
+                format!("gzip 파일이 아닙니다: {}", self.path.display()),
+            ));
+        }
+
+        Ok(MultiGzDecoder::new(reader))
+    }
+
+    /// gzip 파일을 임시 파일 없이 스트림에서 바로 풀어 UTF-8 문자열로 반환합니다.
+    /// gzip 형식이 아니거나 압축 해제 결과가 UTF-8이 아니면 `InvalidData` 오류를 반환합니다.
+    pub fn read_gzip_to_string(&self) -> Result<String> {
+        let mut content = String::new();
+        self.open_gzip()?.read_to_string(&mut content).map_err(to_invalid_data)?;
+        Ok(content)
+    }
+
+    /// gzip 파일을 풀면서 한 줄씩 반환하는 반복자를 반환합니다. 줄바꿈 문자는 포함하지 않습니다.
+    /// gzip 형식이 아니면 즉시 `InvalidData` 오류를 반환하며, 도중의 손상은 해당 항목의 오류로 나타납니다.
+    pub fn gzip_lines(&self) -> Result<impl Iterator<Item = Result<String>>> {
+        let lines = BufReader::with_capacity(self.buffer_size, self.open_gzip()?).lines();
+        Ok(lines.map(|line| line.map_err(to_invalid_data)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::tests::setup_test_env;
+    use flate2::write::GzEncoder;
+    use flate2::Compression;
+    use std::fs;
+    use std::io::Write;
+
+    #[test]
+    fn test_gzip_roundtrip() {
+        let test_dir = setup_test_env("test_gzip_roundtrip");
+        let path = test_dir.join("app.log.gz");
+        let text = "first line\n두 번째 줄\nthird line\n";
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(text.as_bytes()).unwrap();
+        fs::write(&path, encoder.finish().unwrap()).unwrap();
+
+        let file = File::new(&path);
+        assert_eq!(file.read_gzip_to_string().unwrap(), text);
+
+        let lines: Vec<String> = file.gzip_lines().unwrap().collect::<Result<_>>().unwrap();
+        assert_eq!(lines, vec!["first line", "두 번째 줄", "third line"]);
+    }
+
+    #[test]
+    fn test_gzip_multiple_members() {
+        let test_dir = setup_test_env("test_gzip_multiple_members");
+        let path = test_dir.join("rotated.log.gz");
+
+        // 두 gzip 멤버를 이어 붙인 파일은 두 멤버의 내용을 모두 돌려주어야 합니다.
+        let mut content = Vec::new();
+        for part in ["first member\n", "second member\n"] {
+            let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+            encoder.write_all(part.as_bytes()).unwrap();
+            content.extend(encoder.finish().unwrap());
+        }
+        fs::write(&path, content).unwrap();
+
+        let file = File::new(&path);
+        assert_eq!(file.read_gzip_to_string().unwrap(), "first member\nsecond member\n");
+        assert_eq!(file.gzip_lines().unwrap().count(), 2);
+    }
+
+    #[test]
+    fn test_gzip_rejects_plain_file() {
+        let test_dir = setup_test_env("test_gzip_rejects_plain_file");
+        let path = test_dir.join("plain.txt");
+        fs::write(&path, "not compressed").unwrap();
+
+        let file = File::new(&path);
+        assert_eq!(file.read_gzip_to_string().unwrap_err().kind(), ErrorKind::InvalidData);
+        assert_eq!(file.gzip_lines().err().unwrap().kind(), ErrorKind::InvalidData);
+    }
+}
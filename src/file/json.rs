@@ -0,0 +1,103 @@
+use std::io::{BufRead, BufReader, Error, ErrorKind, Result};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::File;
+
+impl File {
+    /// 파일을 JSON으로 파싱하여 반환합니다.
+    /// 파싱에 실패하면 `InvalidData` 오류를 반환합니다.
+    pub fn read_json<T: DeserializeOwned>(&self) -> Result<T> {
+        let content = std::fs::read(&self.path)?;
+        serde_json::from_slice(&content).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+
+    /// 값을 JSON으로 직렬화하여 원자적으로 기록합니다.
+    pub fn write_json<T: Serialize>(&self, value: &T) -> Result<()> {
+        let content = serde_json::to_vec_pretty(value).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        self.write_atomic(content)
+    }
+
+    /// 줄 단위 JSON(NDJSON) 파일의 각 줄을 `T`로 지연 파싱하는 반복자를 반환합니다.
+    /// 빈 줄은 건너뛰며, 파싱에 실패한 줄은 줄 번호를 담은 `InvalidData` 오류 항목이 됩니다.
+    pub fn json_lines<T: DeserializeOwned>(&self) -> Result<impl Iterator<Item = Result<T>>> {
+        let reader = BufReader::with_capacity(self.buffer_size, std::fs::File::open(&self.path)?);
+
+        Ok(reader
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !matches!(line, Ok(line) if line.trim().is_empty()))
+            .map(|(index, line)| {
+                serde_json::from_str(&line?).map_err(|e| {
+                    Error::new(ErrorKind::InvalidData, format!("{}번째 줄을 파싱할 수 없습니다: {}", index + 1, e))
+                })
+            }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::tests::setup_test_env;
+    use serde::Deserialize;
+    use std::fs;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        name: String,
+        port: u16,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn test_json_roundtrip() {
+        let test_dir = setup_test_env("test_json_roundtrip");
+        let file = File::new(test_dir.join("config.json"));
+        let config = Config {
+            name: "server".to_string(),
+            port: 8080,
+            tags: vec!["a".to_string(), "b".to_string()],
+        };
+
+        file.write_json(&config).unwrap();
+        assert_eq!(file.read_json::<Config>().unwrap(), config);
+    }
+
+    #[test]
+    fn test_read_json_invalid_data() {
+        let test_dir = setup_test_env("test_read_json_invalid_data");
+        let file_path = test_dir.join("broken.json");
+        fs::write(&file_path, b"{ not json").unwrap();
+
+        let err = File::new(&file_path).read_json::<Config>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn test_json_lines() {
+        let test_dir = setup_test_env("test_json_lines");
+        let file_path = test_dir.join("log.ndjson");
+        let records = [
+            r#"{"name":"a","port":1,"tags":[]}"#,
+            "",
+            r#"{"name":"b","port":2,"tags":[]}"#,
+            r#"{"name":"c","port":3,"tags":["x"]}"#,
+        ];
+        fs::write(&file_path, records.join("\n")).unwrap();
+
+        // 빈 줄은 건너뛰어야 합니다.
+        let configs: Vec<Config> = File::new(&file_path).json_lines().unwrap().collect::<Result<_>>().unwrap();
+        assert_eq!(configs.iter().map(|c| c.port).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(configs[2].tags, vec!["x".to_string()]);
+
+        // 잘못된 줄만 오류가 되고 나머지는 계속 파싱되어야 합니다.
+        let records = [r#"{"name":"a","port":1,"tags":[]}"#, "{ broken", r#"{"name":"c","port":3,"tags":[]}"#];
+        fs::write(&file_path, records.join("\n")).unwrap();
+        let results: Vec<Result<Config>> = File::new(&file_path).json_lines().unwrap().collect();
+        assert_eq!(results.len(), 3);
+        assert!(results[0].is_ok());
+        assert_eq!(results[1].as_ref().unwrap_err().kind(), ErrorKind::InvalidData);
+        assert!(results[2].is_ok());
+    }
+}
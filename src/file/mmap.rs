@@ -0,0 +1,123 @@
+use std::io::Result;
+use std::ops::Deref;
+
+use super::File;
+
+/// 파일을 읽기 전용으로 메모리에 매핑한 영역입니다.
+/// `&[u8]`로 역참조되며, 값이 해제될 때 매핑도 함께 해제됩니다.
+pub struct Mmap {
+    // 빈 파일은 매핑할 수 없으므로 `None`으로 빈 슬라이스를 나타냅니다.
+    inner: Option<memmap2::Mmap>,
+}
+
+impl Deref for Mmap {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        self.inner.as_deref().unwrap_or(&[])
+    }
+}
+
+impl AsRef<[u8]> for Mmap {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl std::fmt::Debug for Mmap {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Mmap").field("len", &self.len()).finish()
+    }
+}
+
+/// [`File::read_adaptive`]가 돌려주는 파일 내용입니다. 두 경우 모두 `&[u8]`로 역참조됩니다.
+#[derive(Debug)]
+pub enum FileContents {
+    /// 임계값 이하의 파일을 메모리로 읽어 들인 내용
+    Buffered(Vec<u8>),
+    /// 임계값보다 큰 파일을 메모리에 매핑한 내용
+    Mapped(Mmap),
+}
+
+impl Deref for FileContents {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        match self {
+            FileContents::Buffered(bytes) => bytes,
+            FileContents::Mapped(mapped) => mapped,
+        }
+    }
+}
+
+impl AsRef<[u8]> for FileContents {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl File {
+    /// 파일을 읽기 전용으로 메모리에 매핑하여 복사 없이 내용에 접근할 수 있게 합니다.
+    /// 빈 파일은 오류 없이 빈 슬라이스로 매핑됩니다. 매핑된 동안 다른 프로세스가 파일을 줄이면
+    /// 접근 시 프로세스가 종료될 수 있으므로, 내용이 바뀌지 않는 파일에만 사용해야 합니다.
+    pub fn map(&self) -> Result<Mmap> {
+        let handle = std::fs::File::open(&self.path)?;
+        if handle.metadata()?.len() == 0 {
+            return Ok(Mmap { inner: None });
+        }
+
+        // SAFETY: 읽기 전용 매핑이며, 파일이 외부에서 변경되지 않는다는 전제는 위 문서에 명시되어 있습니다.
+        let inner = unsafe { memmap2::Mmap::map(&handle)? };
+        Ok(Mmap { inner: Some(inner) })
+    }
+
+    /// 파일 크기가 `mmap_threshold` 바이트보다 크면 메모리에 매핑하고, 그 이하이면 버퍼로 읽습니다.
+    /// 매핑된 내용에는 [`File::map`]과 같은 주의 사항이 적용됩니다.
+    pub fn read_adaptive(&self, mmap_threshold: u64) -> Result<FileContents> {
+        if self.metadata()?.len() > mmap_threshold {
+            Ok(FileContents::Mapped(self.map()?))
+        } else {
+            Ok(FileContents::Buffered(std::fs::read(&self.path)?))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::tests::setup_test_env;
+    use std::fs;
+
+    #[test]
+    fn test_map() {
+        let test_dir = setup_test_env("test_map");
+        let path = test_dir.join("data.bin");
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i % 256) as u8).collect();
+        fs::write(&path, &data).unwrap();
+
+        let mapped = File::new(&path).map().unwrap();
+        assert_eq!(&mapped[..], &data[..]);
+
+        // 빈 파일은 빈 슬라이스로 매핑해야 합니다.
+        fs::write(test_dir.join("empty.bin"), b"").unwrap();
+        assert!(File::new(test_dir.join("empty.bin")).map().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_read_adaptive() {
+        let test_dir = setup_test_env("test_read_adaptive");
+        let path = test_dir.join("data.bin");
+        fs::write(&path, vec![7u8; 100]).unwrap();
+        let file = File::new(&path);
+
+        // 크기가 임계값과 같으면 버퍼로 읽어야 합니다.
+        let contents = file.read_adaptive(100).unwrap();
+        assert!(matches!(contents, FileContents::Buffered(_)));
+        assert_eq!(&contents[..], &[7u8; 100][..]);
+
+        // 임계값보다 크면 매핑해야 합니다.
+        let contents = file.read_adaptive(99).unwrap();
+        assert!(matches!(contents, FileContents::Mapped(_)));
+        assert_eq!(&contents[..], &[7u8; 100][..]);
+    }
+}
@@ -0,0 +1,71 @@
+use std::io::Result;
+use std::path::Path;
+
+use rayon::prelude::*;
+
+use super::{File, read_dir_sorted};
+
+// 디렉터리의 항목을 병렬로 처리하며, 하위 디렉터리도 각각 별도 작업으로 내려갑니다.
+fn par_visit(dir: &Path) -> Result<Vec<File>> {
+    let nested = read_dir_sorted(dir)?
+        .into_par_iter()
+        .map(|entry| {
+            let path = entry.path();
+            let mut files = Vec::new();
+            if entry.file_type()?.is_dir() {
+                files = par_visit(&path)?;
+            }
+            files.insert(0, File::new(path));
+            Ok(files)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(nested.into_iter().flatten().collect())
+}
+
+impl File {
+    /// `walk`와 같은 항목을 여러 스레드에서 디렉터리 단위로 나누어 순회하여 반환합니다.
+    /// 루트 자신은 포함하지 않으며, 심볼릭 링크 디렉터리는 따라가지 않으므로 순환하지 않습니다.
+    /// 반환 순서는 보장하지 않습니다.
+    pub fn par_walk(&self) -> Result<Vec<File>> {
+        self.ensure_dir()?;
+        par_visit(&self.path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::HashAlgorithm;
+    use crate::file::tests::setup_test_env;
+    use std::fs;
+
+    #[test]
+    fn test_par_walk() {
+        let test_dir = setup_test_env("test_par_walk");
+        for dir in ["a/aa", "b/bb/bbb", "c"] {
+            fs::create_dir_all(test_dir.join(dir)).unwrap();
+        }
+        for (i, name) in ["a/1", "a/aa/2", "b/3", "b/bb/bbb/4", "c/5", "6"].iter().enumerate() {
+            fs::write(test_dir.join(name), format!("content {}", i % 4)).unwrap();
+        }
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(&test_dir, test_dir.join("b/loop")).unwrap();
+
+        let root = File::new(&test_dir);
+        let hash_files = |files: Vec<File>| {
+            let mut hashes: Vec<String> = files
+                .par_iter()
+                .filter(|file| file.is_file())
+                .map(|file| file.hash_with(HashAlgorithm::Sha256).unwrap())
+                .collect();
+            hashes.sort();
+            hashes
+        };
+
+        // 병렬 순회로 얻은 해시 다중집합이 직렬 순회와 같아야 합니다.
+        let parallel = root.par_walk().unwrap();
+        assert_eq!(parallel.len(), root.walk().unwrap().len());
+        assert_eq!(hash_files(parallel), hash_files(root.walk().unwrap()));
+    }
+}
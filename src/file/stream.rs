@@ -0,0 +1,49 @@
+use std::io::Result;
+
+use bytes::Bytes;
+use futures_core::Stream;
+use tokio_util::io::ReaderStream;
+
+use super::File;
+
+impl File {
+    /// 파일을 버퍼 크기 단위의 `Bytes` 조각으로 비동기적으로 읽는 스트림을 반환합니다.
+    /// axum이나 hyper의 응답 본문에 바로 넘길 수 있으며, 읽는 도중의 오류는 `Err` 항목으로 전달됩니다.
+    /// 파일은 호출 시점에 열리므로 파일이 없으면 스트림을 만들기 전에 오류를 반환합니다.
+    pub fn byte_stream(&self) -> Result<impl Stream<Item = Result<Bytes>> + use<>> {
+        let handle = tokio::fs::File::from_std(std::fs::File::open(&self.path)?);
+        Ok(ReaderStream::with_capacity(handle, self.buffer_size))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::tests::setup_test_env;
+    use std::fs;
+    use std::io::ErrorKind;
+
+    #[tokio::test]
+    async fn test_byte_stream() {
+        let test_dir = setup_test_env("test_byte_stream");
+        let path = test_dir.join("body.bin");
+        let data: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+        fs::write(&path, &data).unwrap();
+
+        let stream = File::new(&path).with_buffer_size(8 * 1024).byte_stream().unwrap();
+        let mut stream = std::pin::pin!(stream);
+        let mut collected = Vec::new();
+        let mut chunks = 0;
+        while let Some(chunk) = std::future::poll_fn(|cx| stream.as_mut().poll_next(cx)).await {
+            collected.extend_from_slice(&chunk.unwrap());
+            chunks += 1;
+        }
+
+        // 여러 조각으로 나뉘어 오지만 이어 붙이면 원본과 같아야 합니다.
+        assert!(chunks > 1);
+        assert_eq!(collected, data);
+
+        let err = File::new(test_dir.join("missing")).byte_stream().err().unwrap();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+}
@@ -0,0 +1,72 @@
+use std::io::{Error, ErrorKind, Result};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use super::File;
+
+impl File {
+    /// 별도 스레드에서 파일 전체를 읽고, `timeout` 안에 끝나지 않으면 `TimedOut` 오류를 반환합니다.
+    /// 응답 없는 네트워크 파일 시스템에서 호출자가 무한히 멈추지 않게 하기 위한 것으로,
+    /// 시간 초과 후에도 작업 스레드의 읽기는 취소되지 않고 백그라운드에서 계속될 수 있습니다.
+    pub fn read_bytes_timeout(&self, timeout: Duration) -> Result<Vec<u8>> {
+        let path = self.path.clone();
+        let (sender, receiver) = mpsc::channel();
+
+        std::thread::Builder::new()
+            .name("retrotv-file-read".to_string())
+            .spawn(move || {
+                // 시간 초과로 수신자가 사라졌다면 결과는 버려집니다.
+                let _ = sender.send(std::fs::read(path));
+            })?;
+
+        match receiver.recv_timeout(timeout) {
+            Ok(result) => result,
+            Err(mpsc::RecvTimeoutError::Timeout) => Err(Error::new(
+                ErrorKind::TimedOut,
+                format!("읽기가 시간 안에 끝나지 않았습니다: {}", self.path.display()),
+            )),
+            Err(mpsc::RecvTimeoutError::Disconnected) => {
+                Err(Error::other("읽기 스레드가 결과 없이 종료되었습니다"))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::tests::setup_test_env;
+    use std::fs;
+
+    #[test]
+    fn test_read_bytes_timeout_local_file() {
+        let test_dir = setup_test_env("test_read_bytes_timeout_local_file");
+        let file_path = test_dir.join("data.txt");
+        fs::write(&file_path, b"hello").unwrap();
+
+        let file = File::new(&file_path);
+        assert_eq!(file.read_bytes_timeout(Duration::from_secs(5)).unwrap(), b"hello");
+
+        // 없는 파일의 오류는 그대로 전달되어야 합니다.
+        let missing = File::new(test_dir.join("missing"));
+        assert_eq!(missing.read_bytes_timeout(Duration::from_secs(5)).unwrap_err().kind(), ErrorKind::NotFound);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_read_bytes_timeout_expires() {
+        use std::os::unix::ffi::OsStrExt;
+
+        // 쓰는 쪽이 없는 FIFO는 열기에서 멈추므로 느린 파일 시스템을 흉내 낼 수 있습니다.
+        let test_dir = setup_test_env("test_read_bytes_timeout_expires");
+        let fifo_path = test_dir.join("pipe");
+        let c_path = std::ffi::CString::new(fifo_path.as_os_str().as_bytes()).unwrap();
+        assert_eq!(unsafe { libc::mkfifo(c_path.as_ptr(), 0o644) }, 0);
+
+        let err = File::new(&fifo_path).read_bytes_timeout(Duration::from_millis(50)).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::TimedOut);
+
+        // 쓰는 쪽을 열었다 닫아 멈춰 있는 작업 스레드를 풀어 줍니다.
+        drop(fs::OpenOptions::new().write(true).open(&fifo_path).unwrap());
+    }
+}
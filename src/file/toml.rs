@@ -0,0 +1,61 @@
+use std::io::{Error, ErrorKind, Result};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+use super::File;
+
+impl File {
+    /// 파일을 TOML로 파싱하여 반환합니다.
+    /// 문법 오류나 타입 불일치로 파싱에 실패하면 `InvalidData` 오류를 반환합니다.
+    pub fn read_toml<T: DeserializeOwned>(&self) -> Result<T> {
+        let content = std::fs::read_to_string(&self.path)?;
+        ::toml::from_str(&content).map_err(|e| Error::new(ErrorKind::InvalidData, e))
+    }
+
+    /// 값을 TOML로 직렬화하여 원자적으로 기록합니다.
+    pub fn write_toml<T: Serialize>(&self, value: &T) -> Result<()> {
+        let content = ::toml::to_string_pretty(value).map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+        self.write_atomic(content)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::tests::setup_test_env;
+    use serde::Deserialize;
+    use std::fs;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        name: String,
+        port: u16,
+        tags: Vec<String>,
+    }
+
+    #[test]
+    fn test_toml_roundtrip() {
+        let test_dir = setup_test_env("test_toml_roundtrip");
+        let file = File::new(test_dir.join("config.toml"));
+        let config = Config {
+            name: "server".to_string(),
+            port: 8080,
+            tags: vec!["a".to_string(), "b".to_string()],
+        };
+
+        file.write_toml(&config).unwrap();
+        assert!(fs::read_to_string(file.path()).unwrap().contains("port = 8080"));
+        assert_eq!(file.read_toml::<Config>().unwrap(), config);
+    }
+
+    #[test]
+    fn test_read_toml_invalid_data() {
+        let test_dir = setup_test_env("test_read_toml_invalid_data");
+        let file_path = test_dir.join("broken.toml");
+        fs::write(&file_path, "name = \"unterminated").unwrap();
+
+        let err = File::new(&file_path).read_toml::<Config>().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidData);
+    }
+}
@@ -0,0 +1,44 @@
+use std::io::{Error, ErrorKind, Result};
+
+use super::File;
+
+impl File {
+    /// 파일 또는 디렉터리를 영구 삭제하지 않고 운영체제의 휴지통으로 이동합니다.
+    /// 경로가 존재하지 않으면 `NotFound` 오류를 반환합니다.
+    pub fn trash(&self) -> Result<()> {
+        if !self.exists() {
+            return Err(Error::new(
+                ErrorKind::NotFound,
+                format!("경로가 존재하지 않습니다: {}", self.path.display()),
+            ));
+        }
+
+        trash::delete(&self.path).map_err(Error::other)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::tests::setup_test_env;
+    use std::fs;
+
+    #[test]
+    fn test_trash_non_existent() {
+        let err = File::new("path/that/does/not/exist.tmp").trash().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+    }
+
+    // 실제 휴지통을 사용하므로 데스크톱 환경에서만 수동으로 실행합니다.
+    #[test]
+    #[ignore]
+    fn test_trash_moves_file() {
+        let test_dir = setup_test_env("test_trash_moves_file");
+        let file_path = test_dir.join("to_trash.txt");
+        fs::write(&file_path, b"Hello, World!").unwrap();
+
+        let file = File::new(&file_path);
+        file.trash().unwrap();
+        assert!(!file.exists());
+    }
+}
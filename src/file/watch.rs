@@ -0,0 +1,142 @@
+use std::io::{Error, ErrorKind, Result};
+use std::path::PathBuf;
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+use notify::{EventKind, RecursiveMode, Watcher};
+
+use super::File;
+
+/// 감시 중인 경로에서 발생한 변경 하나입니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileEvent {
+    Created(PathBuf),
+    Modified(PathBuf),
+    Removed(PathBuf),
+    /// 종류를 알 수 없는 변경입니다.
+    Other(PathBuf),
+}
+
+/// 파일 감시를 유지하는 가드입니다.
+/// 가드가 해제되면 감시를 멈추고, 모아 둔 이벤트를 마지막으로 전달한 뒤 작업 스레드가 끝날 때까지 기다립니다.
+pub struct WatchGuard {
+    watcher: Option<notify::RecommendedWatcher>,
+    worker: Option<JoinHandle<()>>,
+}
+
+impl Drop for WatchGuard {
+    fn drop(&mut self) {
+        // 감시자를 먼저 해제해야 채널이 닫혀 작업 스레드가 끝납니다.
+        drop(self.watcher.take());
+        if let Some(worker) = self.worker.take() {
+            let _ = worker.join();
+        }
+    }
+}
+
+// notify 이벤트를 경로별 `FileEvent`로 바꿉니다. 읽기 같은 접근 이벤트는 버립니다.
+fn to_file_events(event: notify::Event) -> impl Iterator<Item = FileEvent> {
+    let kind = event.kind;
+    event.paths.into_iter().filter_map(move |path| match kind {
+        EventKind::Access(_) => None,
+        EventKind::Create(_) => Some(FileEvent::Created(path)),
+        EventKind::Modify(_) => Some(FileEvent::Modified(path)),
+        EventKind::Remove(_) => Some(FileEvent::Removed(path)),
+        EventKind::Any | EventKind::Other => Some(FileEvent::Other(path)),
+    })
+}
+
+fn to_io_error(e: notify::Error) -> Error {
+    match e.kind {
+        notify::ErrorKind::Io(io) => io,
+        notify::ErrorKind::PathNotFound => Error::new(ErrorKind::NotFound, "감시할 경로가 없습니다"),
+        _ => Error::other(e),
+    }
+}
+
+impl File {
+    /// 경로(디렉터리이면 하위 트리 전체)를 감시하여 변경 이벤트를 모으고,
+    /// 마지막 이벤트 뒤로 `debounce` 동안 변경이 없으면 모은 이벤트를 한 번에 `callback`으로 전달합니다.
+    /// 편집기가 저장할 때 여러 번 쓰더라도 한 묶음으로 받을 수 있으며, 반환된 가드를 해제하면 감시가 끝납니다.
+    pub fn watch_debounced<F>(&self, debounce: Duration, mut callback: F) -> Result<WatchGuard>
+    where
+        F: FnMut(Vec<FileEvent>) + Send + 'static,
+    {
+        let (sender, receiver) = mpsc::channel::<notify::Result<notify::Event>>();
+        let mut watcher = notify::recommended_watcher(sender).map_err(to_io_error)?;
+        watcher.watch(&self.path, RecursiveMode::Recursive).map_err(to_io_error)?;
+
+        let worker = std::thread::Builder::new()
+            .name("retrotv-file-watch".to_string())
+            .spawn(move || {
+                // 감시 백엔드의 오류 이벤트는 전달할 곳이 없으므로 버립니다.
+                while let Ok(first) = receiver.recv() {
+                    let mut batch: Vec<FileEvent> = first.into_iter().flat_map(to_file_events).collect();
+                    let closed = loop {
+                        match receiver.recv_timeout(debounce) {
+                            Ok(event) => batch.extend(event.into_iter().flat_map(to_file_events)),
+                            Err(RecvTimeoutError::Timeout) => break false,
+                            Err(RecvTimeoutError::Disconnected) => break true,
+                        }
+                    };
+
+                    if !batch.is_empty() {
+                        callback(batch);
+                    }
+                    if closed {
+                        break;
+                    }
+                }
+            })?;
+
+        Ok(WatchGuard { watcher: Some(watcher), worker: Some(worker) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::tests::setup_test_env;
+    use std::fs;
+    use std::sync::{Arc, Mutex};
+
+    #[test]
+    fn test_watch_debounced() {
+        let test_dir = setup_test_env("test_watch_debounced");
+        let file_path = test_dir.join("watched.txt");
+        fs::write(&file_path, b"initial").unwrap();
+
+        let batches = Arc::new(Mutex::new(Vec::new()));
+        let collected = batches.clone();
+        let guard = File::new(&test_dir)
+            .watch_debounced(Duration::from_millis(300), move |batch| collected.lock().unwrap().push(batch))
+            .unwrap();
+
+        // 짧은 간격으로 여러 번 쓰면 쓴 횟수보다 적은 묶음으로 합쳐져 전달되어야 합니다.
+        // 부하가 걸린 환경에서는 묶음이 나뉠 수 있으므로 정확히 하나인지는 확인하지 않습니다.
+        for i in 0..5 {
+            fs::write(&file_path, format!("write {}", i)).unwrap();
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        std::thread::sleep(Duration::from_millis(1000));
+        drop(guard);
+
+        let batches = batches.lock().unwrap();
+        assert!(!batches.is_empty());
+        assert!(batches.len() < 5);
+        // 백엔드마다 변경 종류가 다를 수 있으므로 경로만 확인합니다.
+        assert!(batches.iter().flatten().any(|event| match event {
+            FileEvent::Created(path) | FileEvent::Modified(path) | FileEvent::Removed(path) | FileEvent::Other(path) => {
+                path.ends_with("watched.txt")
+            }
+        }));
+    }
+
+    #[test]
+    fn test_watch_debounced_missing_path() {
+        let test_dir = setup_test_env("test_watch_debounced_missing_path");
+        let result = File::new(test_dir.join("missing")).watch_debounced(Duration::from_millis(10), |_| {});
+        assert_eq!(result.err().map(|e| e.kind()), Some(ErrorKind::NotFound));
+    }
+}
@@ -0,0 +1,55 @@
+use std::ffi::OsString;
+use std::io::Result;
+
+use super::File;
+
+impl File {
+    /// 해당 경로의 확장 속성 값을 반환합니다.
+    /// 속성이 존재하지 않으면 `None`을 반환합니다.
+    pub fn get_xattr(&self, name: &str) -> Result<Option<Vec<u8>>> {
+        xattr::get(&self.path, name)
+    }
+
+    /// 해당 경로에 확장 속성을 설정합니다.
+    /// 이미 존재하는 속성이면 값을 덮어씁니다.
+    pub fn set_xattr<V: AsRef<[u8]>>(&self, name: &str, value: V) -> Result<()> {
+        xattr::set(&self.path, name, value.as_ref())
+    }
+
+    /// 해당 경로에 설정된 확장 속성의 이름 목록을 반환합니다.
+    pub fn list_xattrs(&self) -> Result<Vec<OsString>> {
+        Ok(xattr::list(&self.path)?.collect())
+    }
+
+    /// 해당 경로의 확장 속성을 삭제합니다.
+    pub fn remove_xattr(&self, name: &str) -> Result<()> {
+        xattr::remove(&self.path, name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::file::tests::setup_test_env;
+    use std::fs;
+
+    #[test]
+    fn test_xattr_roundtrip() {
+        let test_dir = setup_test_env("test_xattr_roundtrip");
+        let file_path = test_dir.join("tagged.txt");
+        fs::write(&file_path, b"Hello, World!").unwrap();
+
+        let file = File::new(&file_path);
+
+        // 존재하지 않는 속성은 오류가 아닌 None을 반환해야 합니다.
+        assert_eq!(file.get_xattr("user.missing").unwrap(), None);
+
+        file.set_xattr("user.tag", b"important").unwrap();
+        assert_eq!(file.get_xattr("user.tag").unwrap(), Some(b"important".to_vec()));
+        assert!(file.list_xattrs().unwrap().contains(&OsString::from("user.tag")));
+
+        file.remove_xattr("user.tag").unwrap();
+        assert_eq!(file.get_xattr("user.tag").unwrap(), None);
+        assert!(!file.list_xattrs().unwrap().contains(&OsString::from("user.tag")));
+    }
+}